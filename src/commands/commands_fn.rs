@@ -6,8 +6,5 @@ pub fn is_at_company(game_state: &GameState) -> bool {
 }
 
 pub async fn delete_game_state(client: &Client) {
-    match crate::data::mongodb::delete_game_state(client).await {
-        Ok(_) => {},
-        Err(_) => {},
-    }
+    let _ = crate::data::mongodb::delete_game_state(client).await;
 }
@@ -0,0 +1,106 @@
+use crate::data::mongodb;
+use crate::models::game_data::{DataFormat, GameData};
+use std::fs;
+use std::path::Path;
+
+const USAGE: &str = "Usage:
+  terminal-company data export <file.json|file.toml>
+  terminal-company data import <file.json|file.toml>
+  terminal-company data validate [file.json|file.toml]";
+
+/// Entry point for `terminal-company data ...`. `args` are the arguments
+/// after `data`.
+pub async fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    match args.first().map(|a| a.as_str()) {
+        Some("export") => export(required_path(args)?).await,
+        Some("import") => import(required_path(args)?).await,
+        Some("validate") => match args.get(1) {
+            Some(path) => validate_file(Path::new(path)),
+            None => validate_database().await,
+        },
+        _ => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    }
+}
+
+fn required_path(args: &[String]) -> Result<&Path, Box<dyn std::error::Error>> {
+    match args.get(1) {
+        Some(path) => Ok(Path::new(path)),
+        None => Err(USAGE.into()),
+    }
+}
+
+fn format_for(path: &Path) -> Result<DataFormat, Box<dyn std::error::Error>> {
+    DataFormat::from_path(path).ok_or_else(|| {
+        format!("Unsupported file type '{}': use .json or .toml", path.display()).into()
+    })
+}
+
+pub fn read_game_data(path: &Path) -> Result<GameData, Box<dyn std::error::Error>> {
+    let format = format_for(path)?;
+    let text = fs::read_to_string(path)?;
+    GameData::from_text(&text, format)
+}
+
+/// Prints every validation issue. Returns true if the data is clean.
+fn report_issues(game_data: &GameData) -> bool {
+    let issues = game_data.validate();
+    if issues.is_empty() {
+        println!(
+            "✅ Data is valid: {} moons, {} store items, {} creatures.",
+            game_data.moons.len(),
+            game_data.store_items.len(),
+            game_data.bestiary.len()
+        );
+        return true;
+    }
+
+    println!("⚠️ Found {} problem(s):", issues.len());
+    for issue in &issues {
+        println!("- {}", issue);
+    }
+    false
+}
+
+async fn export(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let format = format_for(path)?;
+    let client = mongodb::init_db().await?;
+    let game_data = mongodb::load_game_data(&client).await?;
+
+    fs::write(path, game_data.to_text(format)?)?;
+    println!("Game data exported to {}.", path.display());
+    Ok(())
+}
+
+async fn import(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let game_data = read_game_data(path)?;
+    if !report_issues(&game_data) {
+        return Err("Import aborted: fix the problems above first.".into());
+    }
+
+    let client = mongodb::init_db().await?;
+    mongodb::save_game_data(&client, &game_data).await?;
+    println!("Game data imported from {}.", path.display());
+    Ok(())
+}
+
+fn validate_file(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let game_data = read_game_data(path)?;
+    if report_issues(&game_data) {
+        Ok(())
+    } else {
+        Err("Validation failed.".into())
+    }
+}
+
+async fn validate_database() -> Result<(), Box<dyn std::error::Error>> {
+    let client = mongodb::init_db().await?;
+    let game_data = mongodb::load_game_data(&client).await?;
+    if report_issues(&game_data) {
+        Ok(())
+    } else {
+        Err("Validation failed.".into())
+    }
+}
@@ -1,9 +1,8 @@
 use crate::data::mongodb;
-use crate::models::types::{GameState, Player, Ship};
+use crate::models::types::{GameState, Player, Quota, Ship};
 use ::mongodb::Client;
 use std::io::{self, Write};
 use std::sync::Arc;
-use std::{thread, time};
 
 pub async fn initialize_game(client: Arc<Client>) -> Result<GameState, Box<dyn std::error::Error>> {
     if let Ok(Some(loaded_state)) = mongodb::load_game_state(&client).await {
@@ -49,15 +48,26 @@ pub async fn handle_registration(
     io::stdin().read_line(&mut role).unwrap();
     let role = role.trim().to_string();
 
+    let game_state = new_game_state(&name, &role);
+
+    mongodb::save_game_state(&client, &game_state)
+        .await
+        .expect("Failed to save initial game state.");
+
+    Ok(game_state)
+}
+
+/// A fresh game for a newly registered operator, docked at the Company.
+pub fn new_game_state(name: &str, role: &str) -> GameState {
     let player = Player {
-        name,
-        role,
+        name: name.to_string(),
+        role: role.to_string(),
         hp: 100,
         inventory: Vec::new(),
         credits: 30,
     };
 
-    let game_state = GameState {
+    GameState {
         id: Some("game_state".to_string()),
         players: vec![player],
         ship: Ship {
@@ -65,38 +75,37 @@ pub async fn handle_registration(
             number_operators_alive: 1,
             upgrades: Vec::new(),
             decorations: Vec::new(),
+            scrap: Vec::new(),
+            storage: Vec::new(),
         },
         turn_number: 1,
+        day: 1,
         is_game_over: false,
         scan_data: std::collections::HashMap::new(),
-    };
-
-    mongodb::save_game_state(&client, &game_state)
-        .await
-        .expect("Failed to save initial game state.");
-
-    Ok(game_state)
+        discovered_creatures: Vec::new(),
+        landed_time: None,
+        facility: None,
+        encounter: None,
+        cart: vec![],
+        seed: rand::random(),
+        quota: Quota::default(),
+        ledger: vec![],
+    }
 }
 
+/// Scrolled past on the TUI boot screen.
+pub const BOOT_DOCUMENTS: [&str; 7] = [
+    "DOC: INSUFFICIENT FUNDS, LIABILITIES OUTWEIGH ASSETS",
+    "DOC: COMPANY POLICY 11B-3, ARTICLE 4: NO REFUNDS ON DECEASED OPERATORS",
+    "DOC: CONTRACTOR DEBT ACCUMULATED: 92837 CREDITS",
+    "DOC: LETHAL COMPANY AGREEMENT VERIFIED: MINIMUM REVENUE MET",
+    "DOC: ALL ASSETS ARE THE SOLE PROPERTY OF THE COMPANY",
+    "DOC: PERSONNEL DATA RETENTION: 98.7% CHANCE OF MORTALITY",
+    "DOC: DEBT COLLECTION AUTOMATION IN PROGRESS",
+];
+
 pub async fn handle_intro() -> bool {
-    println!("Booting Terminal Company OS...");
     println!("Welcome to Terminal Company.");
-
-    let suspicious_documents = vec![
-        "DOC: INSUFFICIENT FUNDS, LIABILITIES OUTWEIGH ASSETS",
-        "DOC: COMPANY POLICY 11B-3, ARTICLE 4: NO REFUNDS ON DECEASED OPERATORS",
-        "DOC: CONTRACTOR DEBT ACCUMULATED: 92837 CREDITS",
-        "DOC: LETHAL COMPANY AGREEMENT VERIFIED: MINIMUM REVENUE MET",
-        "DOC: ALL ASSETS ARE THE SOLE PROPERTY OF THE COMPANY",
-        "DOC: PERSONNEL DATA RETENTION: 98.7% CHANCE OF MORTALITY",
-        "DOC: DEBT COLLECTION AUTOMATION IN PROGRESS",
-    ];
-
-    for doc in suspicious_documents {
-        println!("{}", doc);
-        thread::sleep(time::Duration::from_millis(200));
-    }
-
     println!("-------------------------------------------------------------");
     println!("Before proceeding, you must accept the Terms and Conditions.");
     println!("Type 'ACCEPT' to continue or 'DENY' to exit.");
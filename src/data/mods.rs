@@ -0,0 +1,211 @@
+use crate::derive_struct;
+use crate::models::game_data::{DataFormat, GameData};
+use crate::models::types::{Item, Monster};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_MODS_DIR: &str = "mods";
+const BUILT_IN: &str = "built-in";
+
+derive_struct! {
+/// A content pack: one `.json` or `.toml` file in the mods directory.
+pub struct ContentPack {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub moons: Vec<String>,
+    #[serde(default)]
+    pub store_items: Vec<Item>,
+    #[serde(default)]
+    pub bestiary: Vec<Monster>,
+}}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContentKind {
+    Moon,
+    Item,
+    Monster,
+}
+
+impl fmt::Display for ContentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentKind::Moon => write!(f, "moon"),
+            ContentKind::Item => write!(f, "item"),
+            ContentKind::Monster => write!(f, "monster"),
+        }
+    }
+}
+
+/// An entry provided by more than one source. Items and monsters from the
+/// later source replace the earlier ones; a repeated moon is left as is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModConflict {
+    pub kind: ContentKind,
+    pub name: String,
+    pub previous_source: String,
+    pub overridden_by: String,
+}
+
+impl fmt::Display for ModConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} '{}' from {} overridden by {}",
+            self.kind, self.name, self.previous_source, self.overridden_by
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackSummary {
+    pub name: String,
+    pub version: Option<String>,
+    pub moons: usize,
+    pub store_items: usize,
+    pub bestiary: usize,
+}
+
+/// Everything the MODS command needs to show about the startup merge.
+#[derive(Debug, Clone, Default)]
+pub struct ModReport {
+    pub packs: Vec<PackSummary>,
+    pub conflicts: Vec<ModConflict>,
+    pub errors: Vec<String>,
+}
+
+/// The mods directory, overridable with `TERMINAL_COMPANY_MODS_DIR`.
+pub fn mods_dir() -> PathBuf {
+    std::env::var("TERMINAL_COMPANY_MODS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_MODS_DIR))
+}
+
+/// Reads every pack in `dir`, sorted by file name. A missing directory means
+/// no mods; unreadable packs are skipped and reported as errors.
+pub fn load_packs(dir: &Path) -> (Vec<ContentPack>, Vec<String>) {
+    let mut packs = Vec::new();
+    let mut errors = Vec::new();
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return (packs, errors);
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && DataFormat::from_path(path).is_some())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        match read_pack(&path) {
+            Ok(pack) => packs.push(pack),
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    (packs, errors)
+}
+
+fn read_pack(path: &Path) -> Result<ContentPack, Box<dyn std::error::Error>> {
+    let text = fs::read_to_string(path)?;
+    let mut pack: ContentPack = match DataFormat::from_path(path) {
+        Some(DataFormat::Json) => serde_json::from_str(&text)?,
+        Some(DataFormat::Toml) => toml::from_str(&text)?,
+        None => return Err("unsupported file type".into()),
+    };
+
+    if pack.name.trim().is_empty() {
+        pack.name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+    }
+    Ok(pack)
+}
+
+/// Merges `packs` into `game_data` in order, so later packs win.
+pub fn apply_packs(game_data: &mut GameData, packs: &[ContentPack]) -> ModReport {
+    let mut report = ModReport::default();
+    let mut sources: HashMap<(u8, String), String> = HashMap::new();
+
+    let key = |kind: ContentKind, name: &str| (kind as u8, name.to_uppercase());
+    for moon in &game_data.moons {
+        sources.insert(key(ContentKind::Moon, moon), BUILT_IN.to_string());
+    }
+    for item in &game_data.store_items {
+        sources.insert(key(ContentKind::Item, &item.name), BUILT_IN.to_string());
+    }
+    for monster in &game_data.bestiary {
+        sources.insert(key(ContentKind::Monster, &monster.name), BUILT_IN.to_string());
+    }
+
+    for pack in packs {
+        let mut record = |kind: ContentKind, name: &str| -> bool {
+            let previous = sources.insert(key(kind, name), pack.name.clone());
+            if let Some(previous_source) = previous {
+                report.conflicts.push(ModConflict {
+                    kind,
+                    name: name.to_string(),
+                    previous_source,
+                    overridden_by: pack.name.clone(),
+                });
+                return true;
+            }
+            false
+        };
+
+        for moon in &pack.moons {
+            if !record(ContentKind::Moon, moon) {
+                game_data.moons.push(moon.to_uppercase());
+            }
+        }
+
+        for item in &pack.store_items {
+            if record(ContentKind::Item, &item.name) {
+                game_data.store_items.retain(|i| !i.name.eq_ignore_ascii_case(&item.name));
+            }
+            game_data.store_items.push(item.clone());
+        }
+
+        for monster in &pack.bestiary {
+            if record(ContentKind::Monster, &monster.name) {
+                game_data.bestiary.retain(|m| !m.name.eq_ignore_ascii_case(&monster.name));
+            }
+            game_data.bestiary.push(monster.clone());
+        }
+
+        report.packs.push(PackSummary {
+            name: pack.name.clone(),
+            version: pack.version.clone(),
+            moons: pack.moons.len(),
+            store_items: pack.store_items.len(),
+            bestiary: pack.bestiary.len(),
+        });
+    }
+
+    report
+}
+
+/// Loads the packs in `dir` into `game_data`. Validation problems in the
+/// merged data are reported as errors but do not stop the game.
+pub fn load_into(game_data: &mut GameData, dir: &Path) -> ModReport {
+    let (packs, errors) = load_packs(dir);
+    let mut report = apply_packs(game_data, &packs);
+    report.errors = errors;
+
+    if !packs.is_empty() {
+        for issue in game_data.validate() {
+            report.errors.push(issue.to_string());
+        }
+    }
+
+    report
+}
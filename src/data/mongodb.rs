@@ -1,4 +1,5 @@
-use crate::models::types::{CollectConfig, GameState, Bestiary};
+use crate::models::game_data::GameData;
+use crate::models::types::{Catalog, CollectConfig, GameState, Bestiary, StoreConfig};
 use log::{error, info};
 use mongodb::bson::doc;
 use mongodb::options::ReplaceOptions;
@@ -66,10 +67,7 @@ pub async fn save_game_state(
         }
         Err(e) => {
             error!("Error saving game_state: {:?}", e);
-            Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Error saving game_state",
-            )))
+            Err(Box::new(std::io::Error::other("Error saving game_state")))
         }
     }
 }
@@ -116,9 +114,26 @@ pub async fn load_collect_config(
     if let Some(config) = coll.find_one(filter.clone()).await? {
         Ok(config)
     } else {
-        let mut default_config = CollectConfig::default();
-        
-        default_config.id = Some("collect_config".to_string());
+        let default_config = CollectConfig {
+            id: Some("collect_config".to_string()),
+            ..CollectConfig::default()
+        };
+        coll.insert_one(default_config.clone()).await?;
+        Ok(default_config)
+    }
+}
+
+pub async fn load_store_config(
+    client: &Client,
+) -> Result<StoreConfig, Box<dyn std::error::Error>> {
+    let db = client.database("terminal_company");
+    let coll = db.collection::<StoreConfig>("game_config");
+    let filter = doc! { "_id": "store_config" };
+
+    if let Some(config) = coll.find_one(filter.clone()).await? {
+        Ok(config)
+    } else {
+        let default_config = StoreConfig::default();
         coll.insert_one(default_config.clone()).await?;
         Ok(default_config)
     }
@@ -131,13 +146,82 @@ pub async fn load_bestiary(
     let coll = db.collection::<Bestiary>("bestiary");
     let filter = doc! { "_id": "bestiary" };
 
-    if let Some(bestiary) = coll.find_one(filter.clone()).await? {
+    if let Some(mut bestiary) = coll.find_one(filter.clone()).await? {
+        if bestiary.fill_spawn_defaults() {
+            coll.replace_one(filter, bestiary.clone()).await?;
+        }
         Ok(bestiary)
     } else {
-        let mut default_bestiary = Bestiary::default();
-        default_bestiary.monsters = crate::models::lists::BESTIARY.to_vec();
-        default_bestiary.id = Some("bestiary".to_string());
+        let default_bestiary = Bestiary::default_content();
         coll.insert_one(default_bestiary.clone()).await?;
         Ok(default_bestiary)
     }
 }
+
+pub async fn load_catalog(
+    client: &Client,
+) -> Result<Catalog, Box<dyn std::error::Error>> {
+    let db = client.database("terminal_company");
+    let coll = db.collection::<Catalog>("game_config");
+    let filter = doc! { "_id": "catalog" };
+
+    if let Some(catalog) = coll.find_one(filter.clone()).await? {
+        Ok(catalog)
+    } else {
+        let default_catalog = Catalog::default();
+        coll.insert_one(default_catalog.clone()).await?;
+        Ok(default_catalog)
+    }
+}
+
+/// Loads every piece of tunable content, seeding the defaults where missing.
+pub async fn load_game_data(
+    client: &Client,
+) -> Result<GameData, Box<dyn std::error::Error>> {
+    let catalog = load_catalog(client).await?;
+    let bestiary = load_bestiary(client).await?;
+    let collect_config = load_collect_config(client).await?;
+    let store_config = load_store_config(client).await?;
+
+    Ok(GameData::from_parts(catalog, bestiary, collect_config, store_config))
+}
+
+/// Replaces the catalog, bestiary, collect and store configs with `game_data`.
+pub async fn save_game_data(
+    client: &Client,
+    game_data: &GameData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let db = client.database("terminal_company");
+    let options = ReplaceOptions::builder().upsert(true).build();
+
+    db.collection::<Catalog>("game_config")
+        .replace_one(doc! { "_id": "catalog" }, game_data.catalog())
+        .with_options(options.clone())
+        .await?;
+
+    db.collection::<Bestiary>("bestiary")
+        .replace_one(doc! { "_id": "bestiary" }, game_data.bestiary())
+        .with_options(options.clone())
+        .await?;
+
+    let collect_config = CollectConfig {
+        id: Some("collect_config".to_string()),
+        ..game_data.collect_config.clone()
+    };
+    db.collection::<CollectConfig>("game_config")
+        .replace_one(doc! { "_id": "collect_config" }, collect_config)
+        .with_options(options.clone())
+        .await?;
+
+    let store_config = StoreConfig {
+        id: Some("store_config".to_string()),
+        ..game_data.store_config.clone()
+    };
+    db.collection::<StoreConfig>("game_config")
+        .replace_one(doc! { "_id": "store_config" }, store_config)
+        .with_options(options)
+        .await?;
+
+    info!("Game data imported.");
+    Ok(())
+}
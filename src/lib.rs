@@ -1,23 +1,40 @@
 pub mod commands {
     pub mod commands_fn;
+    pub mod data_admin;
     pub mod registration;
 }
 pub mod data {
+    pub mod mods;
     pub mod mongodb;
 }
 pub mod models {
     pub mod types;
     pub mod lists;
     pub mod collect_credits;
+    pub mod encounter;
+    pub mod facility;
+    pub mod items;
+    pub mod ledger;
+    pub mod game_data;
     pub mod scan_logic;
+    pub mod scan_report;
+    pub mod store;
+    pub mod threat;
+    pub mod time_of_day;
 }
 pub mod utils {
     pub mod shortcut;
 }
 pub mod ui {
     pub mod app;
+    pub mod bus;
+    pub mod clipboard;
     pub mod components;
+    pub mod effects;
     pub mod event;
     pub mod inputs;
-    pub mod output_capture;
+    pub mod log;
+    pub mod search;
+    pub mod tasks;
+    pub mod theme;
 }
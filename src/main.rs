@@ -1,9 +1,3 @@
-use crate::commands::registration;
-use crate::data::mongodb;
-use crate::ui::app::App;
-use crate::ui::components;
-use crate::ui::event::{Event, EventHandler};
-use crate::ui::inputs;
 use crossterm::{
     execute,
     event::{EnableMouseCapture, DisableMouseCapture},
@@ -14,37 +8,28 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 use std::sync::Arc;
 use std::time::Duration;
-
-mod commands {
-    pub mod commands_fn;
-    pub mod registration;
-}
-mod data {
-    pub mod mongodb;
-}
-mod models {
-    pub mod types;
-    pub mod lists;
-    pub mod collect_credits;
-    pub mod scan_logic;
-}
-mod utils {
-    pub mod shortcut;
-}
-mod ui {
-    pub mod app;
-    pub mod components;
-    pub mod event;
-    pub mod inputs;
-    pub mod output_capture;
-}
+use terminal_company::commands::{data_admin, registration};
+use terminal_company::data::{mods, mongodb};
+use terminal_company::ui::app::App;
+use terminal_company::ui::bus;
+use terminal_company::ui::components;
+use terminal_company::ui::event::{Event, EventHandler};
+use terminal_company::ui::inputs;
+use terminal_company::ui::theme::{self, ThemeSet};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
     env_logger::init();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(|a| a.as_str()) == Some("data") {
+        return data_admin::run(&args[1..]).await;
+    }
+
     let client = Arc::new(mongodb::init_db().await?);
+    let mut game_data = mongodb::load_game_data(&client).await?;
+    let mod_report = mods::load_into(&mut game_data, &mods::mods_dir());
     let game_state = registration::initialize_game(client.clone()).await?;
     
     enable_raw_mode()?;
@@ -53,10 +38,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(game_state, client);
-    let event_handler = EventHandler::new(Duration::from_millis(250));
+    let mut app = App::new(game_state, game_data, client);
+    app.set_mod_report(mod_report);
+    app.set_themes(ThemeSet::load(&theme::themes_file()));
+    let size = terminal.size()?;
+    app.on_resize(size.width, size.height);
+    let mut event_handler = EventHandler::new(Duration::from_millis(100));
 
-    let result = run_app(&mut terminal, &mut app, &event_handler).await;
+    let result = run_app(&mut terminal, &mut app, &mut event_handler).await;
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), DisableMouseCapture, LeaveAlternateScreen)?;
@@ -72,17 +61,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
-    event_handler: &EventHandler,
+    event_handler: &mut EventHandler,
 ) -> io::Result<()> {
     loop {
         terminal.draw(|f| components::render(f, app))?;
 
-        match event_handler.next()? {
+        match event_handler.next().await? {
             Event::Key(key) => {
                 inputs::handle_key_event(app, key).await;
             }
-            Event::Resize(_, _) => {}
-            Event::Tick => {}
+            Event::Resize(width, height) => {
+                terminal.autoresize()?;
+                app.on_resize(width, height);
+            }
+            Event::Tick => {
+                app.on_tick();
+            }
+            Event::Bus => {
+                for event in bus::drain() {
+                    inputs::handle_ui_event(app, event);
+                }
+            }
             Event::Mouse(mouse) => {
                 inputs::handle_mouse_event(app, mouse);
             }
@@ -0,0 +1,329 @@
+use crate::derive_struct;
+use crate::models::items::{self, ItemError};
+use crate::models::threat::behavior_factor;
+use crate::models::types::{GameState, Item, Monster};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Health an operator wakes up with after being dragged out of a facility.
+/// Going down is never a free heal.
+pub const REVIVE_HP: u32 = 30;
+
+derive_struct! {
+/// A creature that has noticed the operator and will not let them move on
+/// until they USE an item, RUN or HIDE.
+pub struct Encounter {
+    pub monster: Monster,
+}}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncounterAction {
+    Use(String),
+    Run,
+    Hide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// The creature is knocked out and taken out of the facility.
+    Stunned,
+    /// The operator fought the creature off; it is gone for this visit.
+    DrivenOff,
+    /// The operator got away into a neighbouring room.
+    Escaped,
+    /// The creature lost track of the operator and wandered elsewhere.
+    Hidden,
+    /// Nothing worked and the creature struck back; the encounter goes on.
+    Hurt,
+    /// The operator went down and was dragged back to the ship.
+    Died,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolution {
+    pub outcome: Outcome,
+    pub monster: String,
+    pub damage: u32,
+    /// Room the operator ended up in, when they fled.
+    pub room: Option<String>,
+    /// Set when the item used was consumed or broke.
+    pub item_spent: Option<String>,
+    /// Set when the item was carried but could not be used (e.g. no charge).
+    pub item_failed: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncounterError {
+    NoEncounter,
+    NoSuchItem(String),
+}
+
+impl fmt::Display for EncounterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncounterError::NoEncounter => write!(f, "Nothing is after you right now."),
+            EncounterError::NoSuchItem(name) => write!(f, "You are not carrying a '{}'.", name),
+        }
+    }
+}
+
+impl std::error::Error for EncounterError {}
+
+/// Chance in percent that a creature notices an operator entering its room.
+pub fn engage_chance(monster: &Monster) -> u32 {
+    ((behavior_factor(&monster.behavior) * 60.0) as u32).min(90)
+}
+
+/// How much a creature's speed changes the odds of outrunning it.
+pub fn speed_modifier(monster: &Monster) -> i32 {
+    let speed = monster.speed.as_deref().unwrap_or_default().to_lowercase();
+    if speed.contains("very fast") {
+        -30
+    } else if ["fast", "quick", "snaps"].iter().any(|k| speed.contains(k)) {
+        -20
+    } else if ["slow", "heavy"].iter().any(|k| speed.contains(k)) {
+        15
+    } else {
+        0
+    }
+}
+
+/// Chance in percent of outrunning a creature while carrying enough to
+/// take `weight_penalty` points off.
+pub fn run_chance(monster: &Monster, weight_penalty: u32) -> u32 {
+    (60 + speed_modifier(monster) - weight_penalty as i32).clamp(5, 95) as u32
+}
+
+/// Hunters track their prey; wanderers are easy to duck out of sight from.
+pub fn hide_chance(monster: &Monster) -> u32 {
+    let behavior = monster.behavior.to_lowercase();
+    if ["stalking", "hunting", "haunting"].iter().any(|k| behavior.contains(k)) {
+        20
+    } else if behavior.contains("chasing") || behavior.contains("aggressive") {
+        35
+    } else if behavior.contains("territorial") || behavior.contains("defensive") {
+        70
+    } else {
+        55
+    }
+}
+
+/// Damage a creature does when the operator's action fails.
+pub fn attack_damage(monster: &Monster) -> u32 {
+    (monster.danger_level.unwrap_or(40) / 2).max(5)
+}
+
+/// Rolls whether any creature in the operator's current room engages them.
+/// Does nothing when an encounter is already running.
+pub fn check_engagement<R: Rng + ?Sized>(game_state: &mut GameState, rng: &mut R) -> bool {
+    if game_state.encounter.is_some() {
+        return false;
+    }
+    let Some(facility) = game_state.facility.as_ref() else {
+        return false;
+    };
+
+    let engaged = facility
+        .current()
+        .monsters
+        .iter()
+        .find(|m| rng.random_range(0..100) < engage_chance(m))
+        .cloned();
+
+    match engaged {
+        Some(monster) => {
+            game_state.encounter = Some(Encounter { monster });
+            true
+        }
+        None => false,
+    }
+}
+
+enum ItemEffect {
+    Stun,
+    Strike,
+    Sprint,
+    Useless,
+}
+
+fn item_effect(item: &Item) -> ItemEffect {
+    match item.name.to_lowercase().as_str() {
+        "stun grenade" | "zap gun" => ItemEffect::Stun,
+        "shovel" => ItemEffect::Strike,
+        "tzp-inhalant" => ItemEffect::Sprint,
+        _ => ItemEffect::Useless,
+    }
+}
+
+/// Resolves one action against the running encounter and applies the result
+/// to the game state: items are used up, the operator takes damage or moves,
+/// and the creature leaves the room when dealt with.
+pub fn resolve<R: Rng + ?Sized>(
+    game_state: &mut GameState,
+    action: &EncounterAction,
+    rng: &mut R,
+) -> Result<Resolution, EncounterError> {
+    let monster = game_state
+        .encounter
+        .as_ref()
+        .map(|e| e.monster.clone())
+        .ok_or(EncounterError::NoEncounter)?;
+
+    let mut resolution = Resolution {
+        outcome: Outcome::Hurt,
+        monster: monster.name.clone(),
+        damage: 0,
+        room: None,
+        item_spent: None,
+        item_failed: None,
+    };
+
+    let succeeded = match action {
+        EncounterAction::Run => {
+            let penalty = game_state
+                .players
+                .first()
+                .map_or(0, |p| items::player_weight_penalty(p, game_state.carried_scrap()));
+            rng.random_range(0..100) < run_chance(&monster, penalty)
+        }
+        EncounterAction::Hide => rng.random_range(0..100) < hide_chance(&monster),
+        EncounterAction::Use(name) => use_item(game_state, name, &monster, &mut resolution, rng)?,
+    };
+
+    if succeeded {
+        resolution.outcome = match action {
+            EncounterAction::Run => Outcome::Escaped,
+            EncounterAction::Hide => Outcome::Hidden,
+            EncounterAction::Use(_) => resolution.outcome,
+        };
+        finish(game_state, &monster, &mut resolution, rng);
+        return Ok(resolution);
+    }
+
+    resolution.outcome = Outcome::Hurt;
+    resolution.damage = attack_damage(&monster);
+    let player = game_state.players.first_mut();
+    let hp_left = player.map_or(0, |p| {
+        p.hp = p.hp.saturating_sub(resolution.damage);
+        p.hp
+    });
+
+    if hp_left == 0 {
+        resolution.outcome = Outcome::Died;
+        knock_out(game_state);
+    }
+    Ok(resolution)
+}
+
+/// Applies an item and reports whether it dealt with the creature. Sets the
+/// outcome the item would give on success.
+fn use_item<R: Rng + ?Sized>(
+    game_state: &mut GameState,
+    name: &str,
+    monster: &Monster,
+    resolution: &mut Resolution,
+    rng: &mut R,
+) -> Result<bool, EncounterError> {
+    let player = game_state
+        .players
+        .first_mut()
+        .ok_or_else(|| EncounterError::NoSuchItem(name.to_string()))?;
+    let index = items::find_index(player, name)
+        .map_err(|_| EncounterError::NoSuchItem(name.to_string()))?;
+
+    let item = &mut player.inventory[index];
+    let item_name = item.name.clone();
+    let effect = item_effect(item);
+
+    if !matches!(effect, ItemEffect::Useless)
+        && let Err(ItemError::BatteryDead(_)) = items::spend(item)
+    {
+        resolution.item_failed = Some(item_name);
+        return Ok(false);
+    }
+
+    let succeeded = match effect {
+        ItemEffect::Stun => {
+            resolution.outcome = Outcome::Stunned;
+            // A grenade always lands; aiming a Zap Gun at something fast is harder.
+            item.charge.is_none() || rng.random_range(0..100) < (80 + speed_modifier(monster)) as u32
+        }
+        ItemEffect::Strike => {
+            resolution.outcome = Outcome::DrivenOff;
+            let chance = if monster.danger_level.unwrap_or(40) <= 50 { 70 } else { 25 };
+            rng.random_range(0..100) < chance
+        }
+        ItemEffect::Sprint => {
+            resolution.outcome = Outcome::Escaped;
+            true
+        }
+        ItemEffect::Useless => false,
+    };
+
+    if items::is_spent(item) {
+        player.inventory.remove(index);
+        resolution.item_spent = Some(item_name);
+    }
+    Ok(succeeded)
+}
+
+/// Ends the encounter after a successful action.
+fn finish<R: Rng + ?Sized>(
+    game_state: &mut GameState,
+    monster: &Monster,
+    resolution: &mut Resolution,
+    rng: &mut R,
+) {
+    game_state.encounter = None;
+    let Some(facility) = game_state.facility.as_mut() else {
+        return;
+    };
+
+    let here = facility.current_room;
+    match resolution.outcome {
+        Outcome::Stunned | Outcome::DrivenOff => {
+            let room = &mut facility.rooms[here];
+            if let Some(i) = room.monsters.iter().position(|m| m.name == monster.name) {
+                room.monsters.remove(i);
+            }
+        }
+        Outcome::Escaped => {
+            let exits = &facility.rooms[here].exits;
+            if !exits.is_empty() {
+                facility.current_room = exits[rng.random_range(0..exits.len())];
+                resolution.room = Some(facility.current().name.clone());
+            }
+        }
+        Outcome::Hidden => {
+            // The creature wanders off to some other room, never the entrance.
+            if facility.rooms.len() > 2 {
+                let mut target = here;
+                while target == here {
+                    target = rng.random_range(1..facility.rooms.len());
+                }
+                let room = &mut facility.rooms[here];
+                if let Some(i) = room.monsters.iter().position(|m| m.name == monster.name) {
+                    let wandering = room.monsters.remove(i);
+                    facility.rooms[target].monsters.push(wandering);
+                }
+            }
+        }
+        Outcome::Hurt | Outcome::Died => {}
+    }
+}
+
+/// The operator is dragged back to the ship: whatever they were carrying is
+/// lost, the facility cannot be entered again this visit and they wake up
+/// with `REVIVE_HP` health.
+fn knock_out(game_state: &mut GameState) {
+    game_state.encounter = None;
+    if let Some(facility) = game_state.facility.take()
+        && let Some(scan) = game_state.scan_data.get_mut(&facility.moon)
+    {
+        scan.depleted = true;
+    }
+    if let Some(player) = game_state.players.first_mut() {
+        player.hp = REVIVE_HP;
+    }
+}
@@ -0,0 +1,199 @@
+use crate::derive_struct;
+use crate::models::time_of_day::{scan_at_time, spawn_condition, Placement};
+use crate::models::types::{Monster, ScanData, Scrap};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+const ROOM_NAMES: &[&str] = &[
+    "Hallway",
+    "Storage Room",
+    "Boiler Room",
+    "Catwalk",
+    "Office",
+    "Generator Room",
+    "Vault",
+    "Laboratory",
+    "Server Room",
+    "Cafeteria",
+    "Locker Room",
+    "Pipe Maze",
+    "Loading Bay",
+];
+
+const SCRAP_NAMES: &[&str] = &[
+    "Large Axle",
+    "Brass Bell",
+    "Cash Register",
+    "Gold Bar",
+    "Rubber Ducky",
+    "Toy Robot",
+    "Magnifying Glass",
+    "Fancy Lamp",
+    "Laser Pointer",
+    "Metal Sheet",
+    "V-Type Engine",
+    "Whoopie Cushion",
+    "Egg Beater",
+    "Bottles",
+];
+
+pub const ENTRANCE: &str = "Main Entrance";
+
+derive_struct! {
+pub struct Room {
+    pub name: String,
+    pub exits: Vec<usize>,
+    pub scrap: Vec<Scrap>,
+    pub monsters: Vec<Monster>,
+}}
+
+derive_struct! {
+/// The inside of a moon's facility while the operator is exploring it.
+pub struct Facility {
+    pub moon: String,
+    pub rooms: Vec<Room>,
+    pub current_room: usize,
+    /// Scrap picked up but not yet brought back to the ship.
+    pub carried: Vec<Scrap>,
+}}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FacilityError {
+    NoSuchExit(String),
+    NoSuchScrap(String),
+}
+
+impl fmt::Display for FacilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FacilityError::NoSuchExit(name) => write!(f, "There is no way to '{}' from here.", name),
+            FacilityError::NoSuchScrap(name) => write!(f, "There is no '{}' in this room.", name),
+        }
+    }
+}
+
+impl std::error::Error for FacilityError {}
+
+/// Splits `total` into `parts` random positive shares that add up exactly.
+fn split_value(total: u32, parts: usize, rng: &mut StdRng) -> Vec<u32> {
+    let weights: Vec<u32> = (0..parts).map(|_| rng.random_range(1..=10)).collect();
+    let weight_sum: u32 = weights.iter().sum();
+    let mut values: Vec<u32> = weights.iter().map(|w| total * w / weight_sum).collect();
+    let assigned: u32 = values.iter().sum();
+    if let Some(last) = values.last_mut() {
+        *last += total - assigned;
+    }
+    values
+}
+
+impl Facility {
+    /// Builds the facility for a scan. The layout and scrap come from the
+    /// scan's seed, so the same scan always produces the same building.
+    /// Creatures that can be indoors and are active at `minutes` are placed
+    /// in rooms other than the entrance.
+    pub fn generate(moon: &str, scan: &ScanData, minutes: u32) -> Self {
+        let mut rng = StdRng::seed_from_u64(scan.seed.rotate_left(17) ^ 0xFAC1_1174);
+        let tier = crate::models::scan_logic::moon_tier(moon) as usize;
+        let room_count = (4 + tier * 2).min(ROOM_NAMES.len() + 1);
+
+        let mut names: Vec<&str> = ROOM_NAMES.to_vec();
+        names.shuffle(&mut rng);
+
+        let mut rooms: Vec<Room> = std::iter::once(ENTRANCE)
+            .chain(names.into_iter().take(room_count - 1))
+            .map(|name| Room {
+                name: name.to_string(),
+                exits: vec![],
+                scrap: vec![],
+                monsters: vec![],
+            })
+            .collect();
+
+        // A random spanning tree keeps every room reachable...
+        for i in 1..rooms.len() {
+            let j = rng.random_range(0..i);
+            rooms[i].exits.push(j);
+            rooms[j].exits.push(i);
+        }
+        // ...and a few extra corridors make loops.
+        for _ in 0..tier {
+            let a = rng.random_range(1..rooms.len());
+            let b = rng.random_range(1..rooms.len());
+            if a != b && !rooms[a].exits.contains(&b) {
+                rooms[a].exits.push(b);
+                rooms[b].exits.push(a);
+            }
+        }
+
+        let scrap_count = room_count - 1;
+        for value in split_value(scan.scrap_value, scrap_count, &mut rng) {
+            let room = rng.random_range(1..rooms.len());
+            let name = SCRAP_NAMES[rng.random_range(0..SCRAP_NAMES.len())];
+            rooms[room].scrap.push(Scrap {
+                name: name.to_string(),
+                value,
+                weight: rng.random_range(5..=40) as f32,
+            });
+        }
+
+        let active = scan_at_time(scan, minutes);
+        for monster in active.monsters {
+            if spawn_condition(&monster).placement == Placement::Outdoor {
+                continue;
+            }
+            let room = rng.random_range(1..rooms.len());
+            rooms[room].monsters.push(monster);
+        }
+
+        Facility {
+            moon: moon.to_string(),
+            rooms,
+            current_room: 0,
+            carried: vec![],
+        }
+    }
+
+    pub fn current(&self) -> &Room {
+        &self.rooms[self.current_room]
+    }
+
+    pub fn exits(&self) -> Vec<&Room> {
+        self.current().exits.iter().map(|i| &self.rooms[*i]).collect()
+    }
+
+    pub fn at_entrance(&self) -> bool {
+        self.current_room == 0
+    }
+
+    pub fn move_to(&mut self, room_name: &str) -> Result<&Room, FacilityError> {
+        let target = self
+            .current()
+            .exits
+            .iter()
+            .copied()
+            .find(|i| self.rooms[*i].name.eq_ignore_ascii_case(room_name))
+            .ok_or_else(|| FacilityError::NoSuchExit(room_name.to_string()))?;
+
+        self.current_room = target;
+        Ok(self.current())
+    }
+
+    /// Moves a piece of scrap from the current room into the operator's hands.
+    pub fn grab(&mut self, scrap_name: &str) -> Result<Scrap, FacilityError> {
+        let room = &mut self.rooms[self.current_room];
+        let index = room
+            .scrap
+            .iter()
+            .position(|s| s.name.eq_ignore_ascii_case(scrap_name))
+            .ok_or_else(|| FacilityError::NoSuchScrap(scrap_name.to_string()))?;
+
+        let scrap = room.scrap.remove(index);
+        self.carried.push(scrap.clone());
+        Ok(scrap)
+    }
+
+    pub fn carried_value(&self) -> u32 {
+        self.carried.iter().map(|s| s.value).sum()
+    }
+}
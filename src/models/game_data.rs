@@ -0,0 +1,189 @@
+use crate::derive_struct;
+use crate::models::types::{Bestiary, Catalog, CollectConfig, Item, Monster, StoreConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+derive_struct! {
+/// Every piece of tunable content in one document, as exchanged with
+/// designers through `terminal-company data export/import`.
+pub struct GameData {
+    pub moons: Vec<String>,
+    pub store_items: Vec<Item>,
+    pub bestiary: Vec<Monster>,
+    pub collect_config: CollectConfig,
+    #[serde(default)]
+    pub store_config: StoreConfig,
+}}
+
+impl Default for GameData {
+    fn default() -> Self {
+        GameData::from_parts(
+            Catalog::default(),
+            Bestiary::default_content(),
+            CollectConfig::default(),
+            StoreConfig::default(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataFormat {
+    Json,
+    Toml,
+}
+
+impl DataFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "json" => Some(DataFormat::Json),
+            "toml" => Some(DataFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    UnknownMoon { monster: String, moon: String },
+    NegativePowerLevel { monster: String, power_level: f32 },
+    NegativeWeight { item: String, weight: f32 },
+    DuplicateMoon(String),
+    DuplicateItem(String),
+    DuplicateMonster(String),
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::UnknownMoon { monster, moon } => {
+                write!(f, "monster '{}' lists unknown moon '{}'", monster, moon)
+            }
+            ValidationIssue::NegativePowerLevel { monster, power_level } => {
+                write!(f, "monster '{}' has negative power level {}", monster, power_level)
+            }
+            ValidationIssue::NegativeWeight { item, weight } => {
+                write!(f, "item '{}' has negative weight {}", item, weight)
+            }
+            ValidationIssue::DuplicateMoon(name) => write!(f, "duplicate moon '{}'", name),
+            ValidationIssue::DuplicateItem(name) => write!(f, "duplicate store item '{}'", name),
+            ValidationIssue::DuplicateMonster(name) => write!(f, "duplicate monster '{}'", name),
+        }
+    }
+}
+
+/// Names are compared case-insensitively, like every lookup in the game.
+fn find_duplicates<'a>(names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+    for name in names {
+        if !seen.insert(name.to_uppercase()) {
+            duplicates.push(name.to_string());
+        }
+    }
+    duplicates
+}
+
+impl GameData {
+    pub fn from_parts(
+        catalog: Catalog,
+        bestiary: Bestiary,
+        collect_config: CollectConfig,
+        store_config: StoreConfig,
+    ) -> Self {
+        GameData {
+            moons: catalog.moons,
+            store_items: catalog.store_items,
+            bestiary: bestiary.monsters,
+            collect_config: CollectConfig {
+                id: None,
+                ..collect_config
+            },
+            store_config: StoreConfig {
+                id: None,
+                ..store_config
+            },
+        }
+    }
+
+    pub fn catalog(&self) -> Catalog {
+        Catalog {
+            id: Some("catalog".to_string()),
+            moons: self.moons.clone(),
+            store_items: self.store_items.clone(),
+        }
+    }
+
+    pub fn bestiary(&self) -> Bestiary {
+        Bestiary {
+            id: Some("bestiary".to_string()),
+            monsters: self.bestiary.clone(),
+        }
+    }
+
+    pub fn is_known_moon(&self, moon: &str) -> bool {
+        self.moons.iter().any(|m| m.eq_ignore_ascii_case(moon))
+    }
+
+    pub fn find_item(&self, name: &str) -> Option<&Item> {
+        self.store_items.iter().find(|i| i.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for moon in find_duplicates(self.moons.iter().map(|m| m.as_str())) {
+            issues.push(ValidationIssue::DuplicateMoon(moon));
+        }
+        for item in find_duplicates(self.store_items.iter().map(|i| i.name.as_str())) {
+            issues.push(ValidationIssue::DuplicateItem(item));
+        }
+        for monster in find_duplicates(self.bestiary.iter().map(|m| m.name.as_str())) {
+            issues.push(ValidationIssue::DuplicateMonster(monster));
+        }
+
+        for item in &self.store_items {
+            if item.weight < 0.0 {
+                issues.push(ValidationIssue::NegativeWeight {
+                    item: item.name.clone(),
+                    weight: item.weight,
+                });
+            }
+        }
+
+        for monster in &self.bestiary {
+            if monster.power_level < 0.0 {
+                issues.push(ValidationIssue::NegativePowerLevel {
+                    monster: monster.name.clone(),
+                    power_level: monster.power_level,
+                });
+            }
+            for moon in &monster.moons {
+                if !self.is_known_moon(moon) {
+                    issues.push(ValidationIssue::UnknownMoon {
+                        monster: monster.name.clone(),
+                        moon: moon.clone(),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
+    pub fn to_text(&self, format: DataFormat) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(match format {
+            DataFormat::Json => serde_json::to_string_pretty(self)?,
+            DataFormat::Toml => toml::to_string_pretty(self)?,
+        })
+    }
+
+    pub fn from_text(text: &str, format: DataFormat) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(match format {
+            DataFormat::Json => serde_json::from_str(text)?,
+            DataFormat::Toml => toml::from_str(text)?,
+        })
+    }
+}
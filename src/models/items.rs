@@ -0,0 +1,234 @@
+use crate::models::types::{EquipSlot, Item, Player, Scrap};
+use std::fmt;
+
+/// Battery charge a single use of a battery-powered item costs.
+pub const CHARGE_PER_USE: u32 = 25;
+pub const FULL_CHARGE: u32 = 100;
+
+pub const HAND_SLOTS: usize = 4;
+/// Extra items a worn Belt Bag holds beyond the hand slots.
+pub const BELT_BAG_CAPACITY: usize = 4;
+/// Weight an operator can carry before they start slowing down.
+pub const FREE_CARRY_WEIGHT: f32 = 20.0;
+pub const MAX_WEIGHT_PENALTY: u32 = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemError {
+    NotCarried(String),
+    NotEquippable(String),
+    AlreadyEquipped(String),
+    NoBattery(String),
+    BatteryDead(String),
+    InventoryFull(String),
+    BagNotEmpty,
+    NotInStorage(String),
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ItemError::NotCarried(name) => write!(f, "You are not carrying a '{}'.", name),
+            ItemError::NotEquippable(name) => write!(f, "The {} can't be equipped.", name),
+            ItemError::AlreadyEquipped(name) => write!(f, "The {} is already equipped.", name),
+            ItemError::NoBattery(name) => write!(f, "The {} doesn't run on batteries.", name),
+            ItemError::BatteryDead(name) => write!(f, "The {}'s battery is dead. CHARGE it aboard the ship.", name),
+            ItemError::InventoryFull(name) => write!(f, "No free slot for the {}. DROP something first.", name),
+            ItemError::BagNotEmpty => write!(f, "Empty the Belt Bag before getting rid of it."),
+            ItemError::NotInStorage(name) => write!(f, "There is no '{}' in the ship's storage.", name),
+        }
+    }
+}
+
+impl std::error::Error for ItemError {}
+
+/// What is left of an item after it has been used once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemUse {
+    pub name: String,
+    /// The item was used up or broke and has left the inventory.
+    pub spent: bool,
+    pub uses_remaining: Option<u32>,
+    pub charge: Option<u32>,
+}
+
+pub fn find_index(player: &Player, name: &str) -> Result<usize, ItemError> {
+    player
+        .inventory
+        .iter()
+        .position(|i| i.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| ItemError::NotCarried(name.to_string()))
+}
+
+/// Takes one use out of an item: battery items lose `CHARGE_PER_USE`, tools
+/// lose a point of durability. Fails without changing anything when the
+/// battery can't cover the use.
+pub fn spend(item: &mut Item) -> Result<(), ItemError> {
+    if let Some(charge) = item.charge.as_mut() {
+        if *charge < CHARGE_PER_USE {
+            return Err(ItemError::BatteryDead(item.name.clone()));
+        }
+        *charge -= CHARGE_PER_USE;
+    }
+    if let Some(uses) = item.uses_remaining.as_mut() {
+        *uses = uses.saturating_sub(1);
+    }
+    Ok(())
+}
+
+/// A consumable is gone after one use; a tool is gone once it wears out.
+pub fn is_spent(item: &Item) -> bool {
+    item.consumable || item.uses_remaining == Some(0)
+}
+
+/// Uses an item outside of an encounter and removes it if it is spent.
+pub fn use_item(player: &mut Player, name: &str) -> Result<ItemUse, ItemError> {
+    let index = find_index(player, name)?;
+    let item = &mut player.inventory[index];
+    spend(item)?;
+
+    let spent = is_spent(item);
+    let result = ItemUse {
+        name: item.name.clone(),
+        spent,
+        uses_remaining: item.uses_remaining,
+        charge: item.charge,
+    };
+    if spent {
+        player.inventory.remove(index);
+    }
+    Ok(result)
+}
+
+/// Equips an item, unequipping whatever held its slot before. Returns the
+/// name of the item that was put away, if any.
+pub fn equip(player: &mut Player, name: &str) -> Result<Option<String>, ItemError> {
+    let index = find_index(player, name)?;
+    let item = &player.inventory[index];
+    let slot = item.slot.ok_or_else(|| ItemError::NotEquippable(item.name.clone()))?;
+    if item.equipped {
+        return Err(ItemError::AlreadyEquipped(item.name.clone()));
+    }
+
+    let mut previous = None;
+    for other in player.inventory.iter_mut() {
+        if other.equipped && other.slot == Some(slot) {
+            other.equipped = false;
+            previous = Some(other.name.clone());
+        }
+    }
+    player.inventory[index].equipped = true;
+    Ok(previous)
+}
+
+/// Drops an item. The last Belt Bag stays on while it still holds items; a
+/// spare one can always be dropped.
+pub fn drop_item(player: &mut Player, name: &str) -> Result<Item, ItemError> {
+    let index = find_index(player, name)?;
+    let bags = player.inventory.iter().filter(|i| is_bag(i)).count();
+    if is_bag(&player.inventory[index]) && bags == 1 && slots_used(player) > HAND_SLOTS {
+        return Err(ItemError::BagNotEmpty);
+    }
+    let mut item = player.inventory.remove(index);
+    item.equipped = false;
+    Ok(item)
+}
+
+/// Refills a battery item. Returns the charge it had before.
+pub fn recharge(player: &mut Player, name: &str) -> Result<u32, ItemError> {
+    let index = find_index(player, name)?;
+    let item = &mut player.inventory[index];
+    let charge = item.charge.as_mut().ok_or_else(|| ItemError::NoBattery(item.name.clone()))?;
+
+    let before = *charge;
+    *charge = FULL_CHARGE;
+    Ok(before)
+}
+
+/// Runs equipped battery items down by one landed action's worth of drain.
+/// Returns the names of items whose battery has just gone flat.
+pub fn drain_batteries(player: &mut Player) -> Vec<String> {
+    let mut flat = vec![];
+    for item in player.inventory.iter_mut().filter(|i| i.equipped) {
+        if let Some(charge) = item.charge.as_mut()
+            && *charge > 0
+        {
+            *charge = charge.saturating_sub(item.battery_drain);
+            if *charge == 0 {
+                flat.push(item.name.clone());
+            }
+        }
+    }
+    flat
+}
+
+/// The Belt Bag is worn rather than held, so it never takes up a slot.
+pub fn is_bag(item: &Item) -> bool {
+    item.slot == Some(EquipSlot::Belt)
+}
+
+pub fn has_belt_bag(player: &Player) -> bool {
+    player.inventory.iter().any(is_bag)
+}
+
+/// Items in slot order: the first `HAND_SLOTS` are in hand, the rest are in
+/// the Belt Bag.
+pub fn slotted(player: &Player) -> impl Iterator<Item = &Item> {
+    player.inventory.iter().filter(|i| !is_bag(i))
+}
+
+pub fn slots_used(player: &Player) -> usize {
+    slotted(player).count()
+}
+
+pub fn capacity(player: &Player) -> usize {
+    if has_belt_bag(player) {
+        HAND_SLOTS + BELT_BAG_CAPACITY
+    } else {
+        HAND_SLOTS
+    }
+}
+
+/// Puts a new item into the first free slot.
+pub fn add_item(player: &mut Player, item: Item) -> Result<(), ItemError> {
+    if !is_bag(&item) && slots_used(player) >= capacity(player) {
+        return Err(ItemError::InventoryFull(item.name));
+    }
+    player.inventory.push(item);
+    Ok(())
+}
+
+/// Moves an item from the ship's storage into a free slot. Returns its
+/// name; on failure the item stays in storage.
+pub fn take_item(player: &mut Player, storage: &mut Vec<Item>, name: &str) -> Result<String, ItemError> {
+    let index = storage
+        .iter()
+        .position(|i| i.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| ItemError::NotInStorage(name.to_string()))?;
+    let item = storage.remove(index);
+    let taken = item.name.clone();
+    if let Err(e) = add_item(player, item.clone()) {
+        storage.insert(index, item);
+        return Err(e);
+    }
+    Ok(taken)
+}
+
+pub fn carried_weight(player: &Player) -> f32 {
+    player.inventory.iter().map(|i| i.weight).sum()
+}
+
+/// Percentage points taken off collect and escape chances for carrying
+/// `weight` pounds: one point per two pounds over the free allowance.
+pub fn weight_penalty(weight: f32) -> u32 {
+    (((weight - FREE_CARRY_WEIGHT).max(0.0) / 2.0) as u32).min(MAX_WEIGHT_PENALTY)
+}
+
+/// Everything the operator is hauling: their items plus the scrap they are
+/// carrying out of the facility.
+pub fn load_weight(player: &Player, scrap: &[Scrap]) -> f32 {
+    carried_weight(player) + scrap.iter().map(|s| s.weight).sum::<f32>()
+}
+
+pub fn player_weight_penalty(player: &Player, scrap: &[Scrap]) -> u32 {
+    weight_penalty(load_weight(player, scrap))
+}
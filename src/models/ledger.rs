@@ -0,0 +1,146 @@
+use crate::derive_struct;
+use crate::models::types::GameState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionKind {
+    Purchase,
+    Refund,
+    ScrapSale,
+    Collect,
+    Rescan,
+}
+
+impl TransactionKind {
+    pub const ALL: [TransactionKind; 5] = [
+        TransactionKind::Purchase,
+        TransactionKind::Refund,
+        TransactionKind::ScrapSale,
+        TransactionKind::Collect,
+        TransactionKind::Rescan,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransactionKind::Purchase => "PURCHASE",
+            TransactionKind::Refund => "REFUND",
+            TransactionKind::ScrapSale => "SCRAP",
+            TransactionKind::Collect => "COLLECT",
+            TransactionKind::Rescan => "RESCAN",
+        }
+    }
+
+    pub fn parse(text: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.label().eq_ignore_ascii_case(text))
+    }
+}
+
+impl fmt::Display for TransactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.label())
+    }
+}
+
+derive_struct! {
+/// One change to the crew's credits. Entries are only ever appended.
+pub struct LedgerEntry {
+    pub turn: u32,
+    pub day: u32,
+    pub location: String,
+    pub kind: TransactionKind,
+    /// Positive for income, negative for spending.
+    pub amount: i64,
+    pub balance: u32,
+    pub note: String,
+}}
+
+impl GameState {
+    /// Appends a ledger entry for a credit change that has already been
+    /// applied to the first player.
+    pub fn record_transaction(&mut self, kind: TransactionKind, amount: i64, note: &str) {
+        let balance = self.players.first().map_or(0, |p| p.credits);
+        self.ledger.push(LedgerEntry {
+            turn: self.turn_number,
+            day: self.day,
+            location: self.ship.location.clone(),
+            kind,
+            amount,
+            balance,
+            note: note.to_string(),
+        });
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LedgerFilter {
+    pub kind: Option<TransactionKind>,
+    pub day: Option<u32>,
+    pub income_only: bool,
+    pub spending_only: bool,
+}
+
+impl LedgerFilter {
+    /// Reads `LEDGER` arguments such as `DAY 3`, `PURCHASE`, `INCOME` or
+    /// `SPENDING`, in any order.
+    pub fn parse(args: &str) -> Result<Self, String> {
+        let mut filter = LedgerFilter::default();
+        let mut words = args.split_whitespace();
+        while let Some(word) = words.next() {
+            match word.to_uppercase().as_str() {
+                "INCOME" => filter.income_only = true,
+                "SPENDING" => filter.spending_only = true,
+                "DAY" => {
+                    let day = words.next().ok_or("DAY needs a number")?;
+                    filter.day = Some(day.parse().map_err(|_| format!("'{}' is not a day number", day))?);
+                }
+                other => {
+                    filter.kind = Some(TransactionKind::parse(other).ok_or_else(|| format!("unknown filter '{}'", other))?);
+                }
+            }
+        }
+        Ok(filter)
+    }
+
+    pub fn matches(&self, entry: &LedgerEntry) -> bool {
+        self.kind.is_none_or(|k| k == entry.kind)
+            && self.day.is_none_or(|d| d == entry.day)
+            && (!self.income_only || entry.amount > 0)
+            && (!self.spending_only || entry.amount < 0)
+    }
+
+    pub fn apply<'a>(&self, entries: &'a [LedgerEntry]) -> Vec<&'a LedgerEntry> {
+        entries.iter().filter(|e| self.matches(e)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DaySummary {
+    pub day: u32,
+    pub income: u64,
+    pub spending: u64,
+}
+
+impl DaySummary {
+    pub fn net(&self) -> i64 {
+        self.income as i64 - self.spending as i64
+    }
+}
+
+/// Income and spending per day, oldest day first.
+pub fn daily_summary(entries: &[&LedgerEntry]) -> Vec<DaySummary> {
+    let mut days: BTreeMap<u32, DaySummary> = BTreeMap::new();
+    for entry in entries {
+        let summary = days.entry(entry.day).or_insert(DaySummary {
+            day: entry.day,
+            ..DaySummary::default()
+        });
+        if entry.amount >= 0 {
+            summary.income += entry.amount as u64;
+        } else {
+            summary.spending += entry.amount.unsigned_abs();
+        }
+    }
+    days.into_values().collect()
+}
@@ -1,5 +1,6 @@
-use crate::models::types::{Item, Monster};
+use crate::models::types::{EquipSlot, Item, Monster};
 use lazy_static::lazy_static;
+use std::collections::HashMap;
 
 pub const MOONS: &[&str] = &[
     "EXPERIMENTATION",
@@ -24,18 +25,36 @@ lazy_static! {
             price: 30,
             weight: 8.0,
             description: String::from("A standard-issue shovel. Digs things."),
+            uses_remaining: Some(10),
+            charge: None,
+            battery_drain: 0,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Spray Paint"),
             price: 50,
             weight: 0.0,
             description: String::from("For marking paths or walls."),
+            uses_remaining: Some(20),
+            charge: None,
+            battery_drain: 0,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Stun Grenade"),
             price: 40,
             weight: 5.0,
             description: String::from("A non-lethal grenade to stun creatures."),
+            uses_remaining: None,
+            charge: None,
+            battery_drain: 0,
+            consumable: true,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("TZP-Inhalant"),
@@ -44,6 +63,12 @@ lazy_static! {
             description: String::from(
                 "Makes you move faster and use less stamina + Squeaky voice."
             ),
+            uses_remaining: None,
+            charge: None,
+            battery_drain: 0,
+            consumable: true,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Walkie-Talkie"),
@@ -52,18 +77,36 @@ lazy_static! {
             description: String::from(
                 "Allows Map Wide Communication (Uses Battery Charge) (Has Large Charge)."
             ),
+            uses_remaining: None,
+            charge: Some(100),
+            battery_drain: 1,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Zap Gun"),
             price: 400,
             weight: 11.0,
             description: String::from("Scans for Monsters and Stuns them (uses battery charge)."),
+            uses_remaining: None,
+            charge: Some(100),
+            battery_drain: 2,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Weed Killer"),
             price: 25,
             weight: 0.0,
             description: String::from("Sprays a non-lethal poison. (Counts as a sound source)"),
+            uses_remaining: Some(10),
+            charge: None,
+            battery_drain: 0,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Company Cruiser"),
@@ -72,6 +115,12 @@ lazy_static! {
             description: String::from(
                 "Can be bought for free one time if destroyed or lost. Cannot be carried."
             ),
+            uses_remaining: None,
+            charge: None,
+            battery_drain: 0,
+            consumable: false,
+            slot: None,
+            equipped: false,
         },
         Item {
             name: String::from("Belt Bag"),
@@ -80,6 +129,12 @@ lazy_static! {
             description: String::from(
                 "A small bag that can be carried in a single hand. Holds 4 items."
             ),
+            uses_remaining: None,
+            charge: None,
+            battery_drain: 0,
+            consumable: false,
+            slot: Some(EquipSlot::Belt),
+            equipped: false,
         },
         Item {
             name: String::from("Survival Kit"),
@@ -88,6 +143,12 @@ lazy_static! {
             description: String::from(
                 "A kit containing 4 Flashlights, 4 Walkie-talkies, and a Shovel."
             ),
+            uses_remaining: None,
+            charge: None,
+            battery_drain: 0,
+            consumable: false,
+            slot: None,
+            equipped: false,
         },
         Item {
             name: String::from("Flashlight"),
@@ -96,6 +157,12 @@ lazy_static! {
             description: String::from(
                 "A small portable light source. (Uses battery charge) (Has Large Charge)"
             ),
+            uses_remaining: None,
+            charge: Some(100),
+            battery_drain: 4,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
         Item {
             name: String::from("Pro-flashlight"),
@@ -104,6 +171,12 @@ lazy_static! {
             description: String::from(
                 "A powerful portable light source. (Uses battery charge) (Has Large Charge)"
             ),
+            uses_remaining: None,
+            charge: Some(100),
+            battery_drain: 2,
+            consumable: false,
+            slot: Some(EquipSlot::Hand),
+            equipped: false,
         },
     ];
 }
@@ -119,6 +192,14 @@ pub const SHIP_UPGRADE: &[&str] = &[
 #[allow(dead_code)]
 pub const SHIP_DECORATIONS: &[&str] = &["Cozy Lights", "Decoy Suit", "Brown Suit", "Purple Suit"];
 
+/// Per-moon spawn weights that override a creature's `rarity`.
+fn spawn_weights(weights: &[(&str, u32)]) -> HashMap<String, u32> {
+    weights
+        .iter()
+        .map(|(moon, weight)| (moon.to_string(), *weight))
+        .collect()
+}
+
 lazy_static! {
     pub static ref BESTIARY: Vec<Monster> = vec![
     Monster {
@@ -138,6 +219,8 @@ lazy_static! {
             ],
             speed: None,
             notes: Some("Highly aggressive when hive is threatened; discharge electricity and attack all living beings.".to_string()),
+            rarity: Some(40),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Baboon Hawk".to_string(),
@@ -156,6 +239,8 @@ lazy_static! {
             ],
             speed: None,
             notes: Some("Tall birds with primate heads; travel in troops with hierarchy and sometimes steal scrap.".to_string()),
+            rarity: Some(35),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Bracken".to_string(),
@@ -179,6 +264,8 @@ lazy_static! {
             ],
             speed: Some("Fast when attacking".to_string()),
             notes: Some("Timid creature that hides in shadows; becomes aggressive when disturbed.".to_string()),
+            rarity: Some(25),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Jester".to_string(),
@@ -197,6 +284,8 @@ lazy_static! {
             ],
             speed: Some("Slow then very fast".to_string()),
             notes: Some("A jack-in-the-box like entity: harmless until a timer or trigger, then deadly.".to_string()),
+            rarity: Some(8),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Forest Guardian".to_string(),
@@ -220,6 +309,8 @@ lazy_static! {
             ],
             speed: Some("Fast, long reach".to_string()),
             notes: Some("Large, fast creature; strong sight but not sound-sensitive; may grab players leaving their ship.".to_string()),
+            rarity: Some(15),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Hygrodere".to_string(),
@@ -243,6 +334,8 @@ lazy_static! {
             ],
             speed: None,
             notes: Some("Slow but relentless; reacts to sound and heat; easy to outrun if moving faster.".to_string()),
+            rarity: Some(30),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Hoarding Bug".to_string(),
@@ -265,6 +358,8 @@ lazy_static! {
             ],
             speed: Some("Fast to enter/out of rooms".to_string()),
             notes: Some("Roams collecting and hoarding scrap; typically non-aggressive until its nest is disturbed.".to_string()),
+            rarity: Some(70),
+            spawn_weights: spawn_weights(&[("EXPERIMENTATION", 90)]),
         },
         Monster {
             name: "Locusts".to_string(),
@@ -282,6 +377,8 @@ lazy_static! {
             ],
             speed: None,
             notes: Some("Harmless insects attracted to light; docile and not a threat.".to_string()),
+            rarity: Some(50),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Fungal Lizard".to_string(),
@@ -305,6 +402,8 @@ lazy_static! {
             ],
             speed: Some("Slow to react".to_string()),
             notes: Some("Herbivore that puffs spores and flees when startled.".to_string()),
+            rarity: Some(40),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Manticoil".to_string(),
@@ -325,6 +424,8 @@ lazy_static! {
             ],
             speed: None,
             notes: Some("Small harmless flying creatures; scatter when disturbed.".to_string()),
+            rarity: Some(60),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Masked Mimic".to_string(),
@@ -341,6 +442,8 @@ lazy_static! {
             ],
             speed: Some("Fast when revealed".to_string()),
             notes: Some("Mimics with theatrical masks; behaves like players, can instantly kill if discovered close.".to_string()),
+            rarity: Some(12),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Trapping Flea".to_string(),
@@ -364,6 +467,8 @@ lazy_static! {
             ],
             speed: Some("Medium, sets traps quickly".to_string()),
             notes: Some("Small adult flea that patrols interiors and constructs traps; attacks swiftly when triggered.".to_string()),
+            rarity: Some(55),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Ghost Girl".to_string(),
@@ -383,6 +488,8 @@ lazy_static! {
             ],
             speed: Some("Fast, persistent".to_string()),
             notes: Some("Invisible to others and obsesses on one target, eventually killing the target with a flying head attack.".to_string()),
+            rarity: Some(5),
+            spawn_weights: spawn_weights(&[("DINE", 25)]),
         },
         Monster {
             name: "Bunker Spider".to_string(),
@@ -406,6 +513,8 @@ lazy_static! {
             ],
             speed: Some("Patrol speed medium".to_string()),
             notes: Some("Large armoured spider with a sting; patrols labyrinthine bunkers and attacks on sight.".to_string()),
+            rarity: Some(45),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Nutcracker".to_string(),
@@ -428,6 +537,8 @@ lazy_static! {
             ],
             speed: Some("Quick reaction".to_string()),
             notes: Some("Nutcracker statues that scan and shoot players; drop firearms/ammo on death.".to_string()),
+            rarity: Some(15),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Blind Hound".to_string(),
@@ -451,6 +562,8 @@ lazy_static! {
             ],
             speed: None,
             notes: Some("Large blind canine that hunts with sound cues; howls alert nearby pack members.".to_string()),
+            rarity: Some(30),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Springhead".to_string(),
@@ -473,6 +586,8 @@ lazy_static! {
             ],
             speed: Some("Starts still, then snaps fast".to_string()),
             notes: Some("Mannequin-like entities that remain still while watched and strike when unwatched; lethal on contact.".to_string()),
+            rarity: Some(20),
+            spawn_weights: HashMap::new(),
         },
         Monster {
             name: "Thumper".to_string(),
@@ -495,6 +610,8 @@ lazy_static! {
             ],
             speed: Some("Large, heavy movement".to_string()),
             notes: Some("Large carnivorous predator; hunts with sight and vibration rather than sound.".to_string()),
+            rarity: Some(35),
+            spawn_weights: HashMap::new(),
         },
     ];
 }
@@ -1,5 +1,8 @@
 use crate::models::types::{Bestiary, Monster, ScanData};
-use rand::{Rng, seq::SliceRandom};
+use rand::Rng;
+
+/// Credits charged for forcing a fresh scan of the current moon.
+pub const RESCAN_COST: u32 = 15;
 
 pub fn moon_tier(moon: &str) -> u8 {
     match moon.to_uppercase().as_str() {
@@ -11,14 +14,59 @@ pub fn moon_tier(moon: &str) -> u8 {
     }
 }
 
+/// Spawn weight used for creatures without a `rarity`.
+pub const DEFAULT_SPAWN_WEIGHT: u32 = 50;
+
 fn monsters_for_moon<'a>(bestiary: &'a Bestiary, moon: &str) -> Vec<&'a Monster> {
     bestiary
         .monsters
         .iter()
-        .filter(|m| m.moons.contains(&moon.to_string()))
+        .filter(|m| m.moons.iter().any(|name| name.eq_ignore_ascii_case(moon)))
         .collect()
 }
 
+/// How likely `monster` is to be picked on `moon` relative to the others.
+pub fn spawn_weight(monster: &Monster, moon: &str) -> u32 {
+    monster
+        .spawn_weights
+        .iter()
+        .find(|(m, _)| m.eq_ignore_ascii_case(moon))
+        .map(|(_, weight)| *weight)
+        .or(monster.rarity)
+        .unwrap_or(DEFAULT_SPAWN_WEIGHT)
+}
+
+/// Total creature power a moon can hold at once, like the original game's
+/// max power per level.
+pub fn max_power(moon: &str) -> f32 {
+    match moon_tier(moon) {
+        1 => 6.0,
+        2 => 10.0,
+        3 => 14.0,
+        4 => 18.0,
+        _ => 6.0,
+    }
+}
+
+fn pick_weighted<'a, R: Rng + ?Sized>(
+    candidates: &[(&'a Monster, u32)],
+    rng: &mut R,
+) -> Option<&'a Monster> {
+    let total: u32 = candidates.iter().map(|(_, weight)| weight).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.random_range(0..total);
+    for (monster, weight) in candidates {
+        if roll < *weight {
+            return Some(monster);
+        }
+        roll -= weight;
+    }
+    None
+}
+
 pub fn danger_value(level: &Option<u32>) -> f32 {
     match level {
         Some(l) => *l as f32 / 20.0,
@@ -26,15 +74,14 @@ pub fn danger_value(level: &Option<u32>) -> f32 {
     }
 }
 
-pub fn calculate_threat_level(monsters: &[&Monster]) -> u32 {
-    monsters
-        .iter()
-        .map(|m| {
-            let base = danger_value(&m.danger_level);
-            let val = (base * m.power_level).ceil() as u32;
-            if val == 0 { 1 } else { val }
-        })
-        .sum()
+/// Threat on a 0-100 scale; see `threat::assess` for the breakdown.
+pub fn calculate_threat_level(monsters: &[&Monster], weather: &str) -> u32 {
+    crate::models::threat::assess(monsters, weather).level
+}
+
+/// A scan only describes the day it was taken on.
+pub fn is_scan_expired(scan: &ScanData, current_day: u32) -> bool {
+    scan.day < current_day
 }
 
 pub fn generate_scan_data(
@@ -43,8 +90,18 @@ pub fn generate_scan_data(
     scrap_value: u32,
     bestiary: &Bestiary,
 ) -> ScanData {
-    let mut rng = rand::rng();
+    generate_scan_data_with_rng(moon, weather, scrap_value, bestiary, &mut rand::rng())
+}
 
+/// Picks creatures by their spawn weight on `moon`, allowing repeats, until
+/// the moon's tier count is reached or no creature fits the power budget.
+pub fn generate_scan_data_with_rng<R: Rng + ?Sized>(
+    moon: &str,
+    weather: &str,
+    scrap_value: u32,
+    bestiary: &Bestiary,
+    rng: &mut R,
+) -> ScanData {
     let candidates = monsters_for_moon(bestiary, moon);
 
     let tier = moon_tier(moon);
@@ -56,30 +113,34 @@ pub fn generate_scan_data(
         _ => 1,
     };
 
-    let mut selected = Vec::new();
-    if !candidates.is_empty() {
-        //let candidates_for_debug = candidates.clone();
-        let mut shuffled: Vec<&Monster> = candidates.into_iter().collect();
-        shuffled.shuffle(&mut rng);
-        selected.extend(shuffled.into_iter().take(max_monsters));
-
-        // eprintln!(
-        //     "[DEBUG] Candidates: {:?}",
-        //     candidates_for_debug.iter().map(|m| &m.name).collect::<Vec<_>>()
-        // );
-        // eprintln!(
-        //     "[DEBUG] Selected monsters for {}: {:?}",
-        //     moon,
-        //     selected.iter().map(|m| &m.name).collect::<Vec<_>>()
-        // );
+    let mut selected: Vec<&Monster> = Vec::new();
+    let mut remaining_power = max_power(moon);
+    while selected.len() < max_monsters {
+        let fitting: Vec<(&Monster, u32)> = candidates
+            .iter()
+            .filter(|m| m.power_level <= remaining_power)
+            .map(|m| (*m, spawn_weight(m, moon)))
+            .collect();
+
+        match pick_weighted(&fitting, rng) {
+            Some(monster) => {
+                remaining_power -= monster.power_level.max(0.0);
+                selected.push(monster);
+            }
+            None => break,
+        }
     }
 
-    let threat_level = calculate_threat_level(&selected);
+    let threat_level = calculate_threat_level(&selected, weather);
 
     ScanData {
         weather: weather.to_string(),
         threat_level,
         scrap_value,
         monsters: selected.into_iter().cloned().collect(),
+        day: 0,
+        depleted: false,
+        seed: rng.random(),
+        revealed: false,
     }
 }
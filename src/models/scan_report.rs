@@ -0,0 +1,104 @@
+use crate::models::types::{Player, ScanData, Ship};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+const BASE_CONFIDENCE: u32 = 40;
+const SCOUT_BONUS: u32 = 20;
+const SIGNAL_TRANSLATOR_BONUS: u32 = 20;
+const UPGRADE_BONUS: u32 = 5;
+const MAX_ESTIMATE_CONFIDENCE: u32 = 95;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sighting {
+    Identified { name: String, danger_level: Option<u32> },
+    UnknownSignature,
+}
+
+/// What the ship's scanner tells the operator about a moon. The true
+/// `ScanData` stays in `GameState`; this is the estimate built from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanReport {
+    pub weather: String,
+    pub confidence: u32,
+    pub threat_range: (u32, u32),
+    pub scrap_range: (u32, u32),
+    pub sightings: Vec<Sighting>,
+}
+
+impl ScanReport {
+    pub fn identified_names(&self) -> Vec<&str> {
+        self.sightings
+            .iter()
+            .filter_map(|s| match s {
+                Sighting::Identified { name, .. } => Some(name.as_str()),
+                Sighting::UnknownSignature => None,
+            })
+            .collect()
+    }
+}
+
+/// Scanner confidence in percent from the operator's role and the ship's upgrades.
+pub fn scan_confidence(player: &Player, ship: &Ship) -> u32 {
+    let mut confidence = BASE_CONFIDENCE;
+
+    if player.role.eq_ignore_ascii_case("Scout") {
+        confidence += SCOUT_BONUS;
+    }
+
+    for upgrade in &ship.upgrades {
+        if upgrade.eq_ignore_ascii_case("Signal Translator") {
+            confidence += SIGNAL_TRANSLATOR_BONUS;
+        } else {
+            confidence += UPGRADE_BONUS;
+        }
+    }
+
+    confidence.min(MAX_ESTIMATE_CONFIDENCE)
+}
+
+/// Returns a range of width proportional to the uncertainty that always
+/// contains `value`, without putting it at a predictable position.
+fn estimate_range(value: u32, confidence: u32, rng: &mut StdRng) -> (u32, u32) {
+    let spread = value * (100 - confidence) / 100;
+    let width = spread * 2;
+    let low = value.saturating_sub(rng.random_range(0..=width));
+    (low, low + width)
+}
+
+/// Builds the report for a scan. Creatures in `known_creatures` are always
+/// identified; the rest are identified with a chance equal to the confidence.
+/// The result is stable for a given scan, so repeated SCANs agree.
+pub fn build_report(scan: &ScanData, confidence: u32, known_creatures: &[String]) -> ScanReport {
+    let confidence = if scan.revealed { 100 } else { confidence.min(100) };
+    let mut rng = StdRng::seed_from_u64(scan.seed);
+
+    let (threat_low, threat_high) = estimate_range(scan.threat_level, confidence, &mut rng);
+    let threat_range = (threat_low, threat_high.min(100));
+    let scrap_range = estimate_range(scan.scrap_value, confidence, &mut rng);
+
+    let sightings = scan
+        .monsters
+        .iter()
+        .map(|monster| {
+            let known = known_creatures
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&monster.name));
+            let roll = rng.random_range(0..100);
+            if known || roll < confidence {
+                Sighting::Identified {
+                    name: monster.name.clone(),
+                    danger_level: monster.danger_level,
+                }
+            } else {
+                Sighting::UnknownSignature
+            }
+        })
+        .collect();
+
+    ScanReport {
+        weather: scan.weather.clone(),
+        confidence,
+        threat_range,
+        scrap_range,
+        sightings,
+    }
+}
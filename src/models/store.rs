@@ -0,0 +1,265 @@
+use crate::derive_struct;
+use crate::models::items;
+use crate::models::types::{GameState, Item, Player, Quota, StoreConfig};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Most of one item a single order can ask for.
+pub const MAX_QUANTITY: u32 = 99;
+
+derive_struct! {
+pub struct CartLine {
+    pub item: Item,
+    pub quantity: u32,
+}}
+
+impl CartLine {
+    pub fn subtotal(&self) -> u32 {
+        self.item.price * self.quantity
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreError {
+    EmptyCart,
+    InvalidQuantity(String),
+    NotEnoughCredits { needed: u32, available: u32 },
+    NothingToSell(String),
+    /// A Belt Bag can't be sold while it still holds items.
+    BagNotEmpty(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::EmptyCart => write!(f, "Your cart is empty. Use 'CART ADD [n] [item]' first."),
+            StoreError::InvalidQuantity(text) => {
+                write!(f, "'{}' is not a valid quantity (1-{}).", text, MAX_QUANTITY)
+            }
+            StoreError::NotEnoughCredits { needed, available } => write!(
+                f,
+                "Not enough credits: the order costs {} but you have only {}.",
+                needed, available
+            ),
+            StoreError::NothingToSell(name) => write!(f, "You have no '{}' to sell.", name),
+            StoreError::BagNotEmpty(name) => write!(f, "Empty the {} before selling it.", name),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// Splits `"3 flashlight"` into a quantity and an item name. Without a
+/// leading number the quantity is 1.
+pub fn parse_order(args: &str) -> Result<(u32, &str), StoreError> {
+    let args = args.trim();
+    let Some((first, rest)) = args.split_once(' ') else {
+        return Ok((1, args));
+    };
+    if !first.chars().all(|c| c.is_ascii_digit()) {
+        return Ok((1, args));
+    }
+
+    match first.parse::<u32>() {
+        Ok(n) if (1..=MAX_QUANTITY).contains(&n) => Ok((n, rest.trim())),
+        _ => Err(StoreError::InvalidQuantity(first.to_string())),
+    }
+}
+
+/// Adds `quantity` of `item`, merging with an existing line. A line never
+/// goes over `MAX_QUANTITY`. Returns the line's quantity afterwards.
+pub fn add_to_cart(cart: &mut Vec<CartLine>, item: Item, quantity: u32) -> u32 {
+    match cart.iter_mut().find(|line| line.item.name == item.name) {
+        Some(line) => {
+            line.quantity = (line.quantity + quantity).min(MAX_QUANTITY);
+            line.quantity
+        }
+        None => {
+            let quantity = quantity.min(MAX_QUANTITY);
+            cart.push(CartLine { item, quantity });
+            quantity
+        }
+    }
+}
+
+pub fn cart_total(cart: &[CartLine]) -> u32 {
+    cart.iter().map(CartLine::subtotal).sum()
+}
+
+/// Buys every line of an order or nothing at all. Items go into the
+/// operator's free slots; whatever doesn't fit is delivered to the ship's
+/// `storage`. Nothing changes unless the credits cover the total. Returns
+/// the credits spent.
+pub fn checkout(player: &mut Player, storage: &mut Vec<Item>, order: &[CartLine]) -> Result<u32, StoreError> {
+    if order.is_empty() {
+        return Err(StoreError::EmptyCart);
+    }
+
+    let total = cart_total(order);
+    if total > player.credits {
+        return Err(StoreError::NotEnoughCredits {
+            needed: total,
+            available: player.credits,
+        });
+    }
+
+    // Bags first, so the space they add counts for the rest of the order
+    let mut lines: Vec<&CartLine> = order.iter().collect();
+    lines.sort_by_key(|line| !items::is_bag(&line.item));
+
+    for line in lines {
+        for _ in 0..line.quantity {
+            if items::add_item(player, line.item.clone()).is_err() {
+                storage.push(line.item.clone());
+            }
+        }
+    }
+
+    player.credits -= total;
+    Ok(total)
+}
+
+/// The store's prices for one day: which items are on sale and by how much,
+/// plus the markup the Company charges for the crew's quota progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyPrices {
+    /// Discount percent by item name.
+    pub sales: HashMap<String, u32>,
+    pub markup: u32,
+}
+
+/// Picks the day's sales. The same seed and day always give the same sales.
+pub fn daily_sales(seed: u64, day: u32, items: &[Item], config: &StoreConfig) -> HashMap<String, u32> {
+    let mut rng = StdRng::seed_from_u64(seed ^ (day as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    let count = rng.random_range(0..=config.max_daily_sales) as usize;
+
+    let mut names: Vec<&str> = items.iter().map(|i| i.name.as_str()).collect();
+    names.shuffle(&mut rng);
+
+    // Discounts come in steps of ten, like the terminal shows them
+    let low = config.min_discount / 10;
+    let high = (config.max_discount / 10).max(low);
+    names
+        .into_iter()
+        .take(count)
+        .map(|name| (name.to_string(), rng.random_range(low..=high) * 10))
+        .collect()
+}
+
+/// Percent the Company adds to prices at the current quota progress.
+pub fn quota_markup(quota: &Quota, config: &StoreConfig) -> u32 {
+    (config.quota_markup as f32 * quota.progress()).round() as u32
+}
+
+impl DailyPrices {
+    pub fn new(seed: u64, day: u32, quota: &Quota, items: &[Item], config: &StoreConfig) -> Self {
+        DailyPrices {
+            sales: daily_sales(seed, day, items, config),
+            markup: quota_markup(quota, config),
+        }
+    }
+
+    pub fn discount(&self, item: &Item) -> Option<u32> {
+        self.sales.get(&item.name).copied()
+    }
+
+    /// Price before the sale, with the quota markup applied.
+    pub fn list_price(&self, item: &Item) -> u32 {
+        item.price * (100 + self.markup) / 100
+    }
+
+    pub fn price(&self, item: &Item) -> u32 {
+        let list = self.list_price(item);
+        match self.discount(item) {
+            Some(discount) => list * (100 - discount.min(100)) / 100,
+            None => list,
+        }
+    }
+
+    /// A copy of `item` carrying today's price, as it goes into a cart or
+    /// an inventory.
+    pub fn priced(&self, item: &Item) -> Item {
+        Item {
+            price: self.price(item),
+            ..item.clone()
+        }
+    }
+}
+
+/// The Company pays full value for scrap on the deadline day and this many
+/// percent less for every day before it.
+pub const BUY_RATE_STEP: u32 = 20;
+pub const MIN_BUY_RATE: u32 = 30;
+
+/// Percent of its value the Company pays for scrap today.
+pub fn buy_rate(quota: &Quota, day: u32) -> u32 {
+    let days_left = quota.deadline_day.saturating_sub(day);
+    100u32.saturating_sub(days_left * BUY_RATE_STEP).max(MIN_BUY_RATE)
+}
+
+pub fn refund_value(item: &Item, config: &StoreConfig) -> u32 {
+    item.price * config.refund_percent.min(100) / 100
+}
+
+/// What a sale paid out, line by line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Receipt {
+    pub lines: Vec<(String, u32)>,
+    pub total: u32,
+    pub balance: u32,
+}
+
+/// Sells an item back to the Company for `refund_percent` of what was paid.
+pub fn sell_item(player: &mut Player, name: &str, config: &StoreConfig) -> Result<Receipt, StoreError> {
+    let item = match items::drop_item(player, name) {
+        Ok(item) => item,
+        Err(items::ItemError::BagNotEmpty) => {
+            let bag = player.inventory.iter().find(|i| i.name.eq_ignore_ascii_case(name));
+            return Err(StoreError::BagNotEmpty(bag.map_or(name.to_string(), |i| i.name.clone())));
+        }
+        Err(_) => return Err(StoreError::NothingToSell(name.to_string())),
+    };
+    let refund = refund_value(&item, config);
+    player.credits += refund;
+
+    Ok(Receipt {
+        lines: vec![(item.name, refund)],
+        total: refund,
+        balance: player.credits,
+    })
+}
+
+/// Sells scrap from the ship at today's buy rate; every piece when `name`
+/// is `None`. The credits count towards the quota.
+pub fn sell_scrap(game_state: &mut GameState, name: Option<&str>) -> Result<Receipt, StoreError> {
+    let nothing = || StoreError::NothingToSell(name.unwrap_or("scrap").to_string());
+    if game_state.players.is_empty() {
+        return Err(nothing());
+    }
+
+    let scrap = &mut game_state.ship.scrap;
+    let sold = match name {
+        Some(name) => {
+            let index = scrap.iter().position(|s| s.name.eq_ignore_ascii_case(name)).ok_or_else(nothing)?;
+            vec![scrap.remove(index)]
+        }
+        None if scrap.is_empty() => return Err(nothing()),
+        None => std::mem::take(scrap),
+    };
+
+    let rate = buy_rate(&game_state.quota, game_state.day);
+    let lines: Vec<(String, u32)> = sold.into_iter().map(|s| (s.name, s.value * rate / 100)).collect();
+    let total: u32 = lines.iter().map(|(_, value)| value).sum();
+
+    game_state.quota.fulfilled += total;
+    let player = &mut game_state.players[0];
+    player.credits += total;
+
+    Ok(Receipt {
+        lines,
+        total,
+        balance: player.credits,
+    })
+}
@@ -0,0 +1,115 @@
+use crate::models::scan_logic::danger_value;
+use crate::models::time_of_day::{spawn_condition, Placement};
+use crate::models::types::Monster;
+
+/// Raw threat at which the normalised level reaches ~63%.
+const THREAT_SCALE: f32 = 12.0;
+/// Each creature beyond the first makes the others more dangerous.
+const STACKING_PER_MONSTER: f32 = 0.1;
+
+/// How much a creature's behaviour raises or lowers its threat. A passive or
+/// docile creature stays harmless whatever else it does; otherwise, when
+/// several behaviours are listed, the most dangerous one counts.
+pub fn behavior_factor(behavior: &str) -> f32 {
+    let behavior = behavior.to_lowercase();
+    if behavior.contains("passive") || behavior.contains("docile") {
+        return 0.3;
+    }
+
+    let mut factor: Option<f32> = None;
+    let mut consider = |keywords: &[&str], value: f32| {
+        if keywords.iter().any(|k| behavior.contains(k)) {
+            factor = Some(factor.map_or(value, |f| f.max(value)));
+        }
+    };
+
+    consider(&["territorial", "defensive", "guarding"], 0.8);
+    consider(&["roaming", "patrolling", "looting", "trooping", "lurking"], 1.0);
+    consider(
+        &["hunting", "chasing", "stalking", "aggressive", "ambush", "haunting", "explosive"],
+        1.3,
+    );
+
+    factor.unwrap_or(1.0)
+}
+
+/// Operators spend most of a visit inside the facility, so indoor creatures
+/// are met more often than outdoor ones.
+pub fn placement_factor(placement: Placement) -> f32 {
+    match placement {
+        Placement::Indoor => 1.2,
+        Placement::Outdoor => 0.8,
+        Placement::Anywhere => 1.0,
+    }
+}
+
+pub fn weather_factor(weather: &str) -> f32 {
+    match weather.to_lowercase().as_str() {
+        "rainy" => 1.1,
+        "foggy" => 1.2,
+        "stormy" => 1.3,
+        "eclipsed" => 1.5,
+        _ => 1.0,
+    }
+}
+
+pub fn stacking_factor(monster_count: usize) -> f32 {
+    1.0 + STACKING_PER_MONSTER * monster_count.saturating_sub(1) as f32
+}
+
+/// Maps an unbounded raw threat onto 0-100. Any creature at all registers
+/// as at least 1.
+pub fn normalise(raw: f32) -> u32 {
+    if raw <= 0.0 {
+        return 0;
+    }
+    (100.0 * (1.0 - (-raw / THREAT_SCALE).exp())).round().clamp(1.0, 100.0) as u32
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreatContribution {
+    pub name: String,
+    pub base: f32,
+    pub behavior_factor: f32,
+    pub placement_factor: f32,
+    pub score: f32,
+}
+
+/// How a threat level was reached, as shown by SCAN DETAIL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreatBreakdown {
+    pub contributions: Vec<ThreatContribution>,
+    pub stacking_factor: f32,
+    pub weather_factor: f32,
+    pub raw_total: f32,
+    pub level: u32,
+}
+
+pub fn contribution(monster: &Monster) -> ThreatContribution {
+    let base = danger_value(&monster.danger_level) * monster.power_level.max(0.0);
+    let behavior = behavior_factor(&monster.behavior);
+    let placement = placement_factor(spawn_condition(monster).placement);
+
+    ThreatContribution {
+        name: monster.name.clone(),
+        base,
+        behavior_factor: behavior,
+        placement_factor: placement,
+        score: base * behavior * placement,
+    }
+}
+
+pub fn assess(monsters: &[&Monster], weather: &str) -> ThreatBreakdown {
+    let contributions: Vec<ThreatContribution> = monsters.iter().map(|m| contribution(m)).collect();
+    let stacking = stacking_factor(contributions.len());
+    let weather = weather_factor(weather);
+    let raw_total = contributions.iter().map(|c| c.score).sum::<f32>() * stacking * weather;
+
+    ThreatBreakdown {
+        contributions,
+        stacking_factor: stacking,
+        weather_factor: weather,
+        raw_total,
+        level: normalise(raw_total),
+    }
+}
@@ -0,0 +1,150 @@
+use crate::models::scan_logic::calculate_threat_level;
+use crate::models::types::{GameState, Monster, ScanData};
+
+/// Times are minutes since midnight of the landed day.
+pub const LANDING_TIME: u32 = 8 * 60;
+pub const NIGHTFALL: u32 = 18 * 60;
+pub const MIDNIGHT: u32 = 24 * 60;
+
+pub const SCAN_MINUTES: u32 = 60;
+pub const COLLECT_MINUTES: u32 = 240;
+pub const WAIT_MINUTES: u32 = 60;
+pub const FACILITY_DOOR_MINUTES: u32 = 10;
+pub const MOVE_MINUTES: u32 = 20;
+pub const GRAB_MINUTES: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Placement {
+    Indoor,
+    Outdoor,
+    Anywhere,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActivePeriod {
+    Day,
+    Night,
+    Always,
+}
+
+/// Structured form of `Monster::spawn_condition`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpawnCondition {
+    pub placement: Placement,
+    pub period: ActivePeriod,
+    /// Weathers in which the creature is active regardless of the hour.
+    pub weather: Vec<String>,
+}
+
+impl SpawnCondition {
+    pub fn parse(condition: &str) -> Self {
+        let text = condition.to_lowercase();
+
+        let indoor = text.contains("inside") || text.contains("indoor");
+        let outdoor = text.contains("outside") || text.contains("outdoor");
+        let placement = if text.contains("anywhere") || (indoor && outdoor) {
+            Placement::Anywhere
+        } else if indoor {
+            Placement::Indoor
+        } else if outdoor {
+            Placement::Outdoor
+        } else {
+            Placement::Anywhere
+        };
+
+        let period = if text.contains("daytime") {
+            ActivePeriod::Day
+        } else if text.contains("night") || text.contains("darkness") {
+            ActivePeriod::Night
+        } else {
+            ActivePeriod::Always
+        };
+
+        let mut weather = Vec::new();
+        if text.contains("eclipse") {
+            weather.push("Eclipsed".to_string());
+        }
+
+        SpawnCondition {
+            placement,
+            period,
+            weather,
+        }
+    }
+
+    pub fn is_active(&self, minutes: u32, weather: &str) -> bool {
+        if self.weather.iter().any(|w| w.eq_ignore_ascii_case(weather)) {
+            return true;
+        }
+
+        match self.period {
+            ActivePeriod::Always => true,
+            // An eclipse keeps the sun from ever really coming up
+            ActivePeriod::Day => is_daytime(minutes) && !weather.eq_ignore_ascii_case("Eclipsed"),
+            ActivePeriod::Night => !is_daytime(minutes),
+        }
+    }
+}
+
+pub fn spawn_condition(monster: &Monster) -> SpawnCondition {
+    SpawnCondition::parse(&monster.spawn_condition)
+}
+
+pub fn is_daytime(minutes: u32) -> bool {
+    minutes < NIGHTFALL
+}
+
+/// Formats minutes since midnight as a 12-hour clock, e.g. "2:30 PM".
+pub fn format_clock(minutes: u32) -> String {
+    let minutes = minutes % MIDNIGHT;
+    let hour = minutes / 60;
+    let suffix = if hour < 12 { "AM" } else { "PM" };
+    let display_hour = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    format!("{}:{:02} {}", display_hour, minutes % 60, suffix)
+}
+
+/// The part of a scan that is active at the given time: only creatures whose
+/// spawn condition matches, with the threat level recomputed for them.
+pub fn scan_at_time(scan: &ScanData, minutes: u32) -> ScanData {
+    let active: Vec<&Monster> = scan
+        .monsters
+        .iter()
+        .filter(|m| spawn_condition(m).is_active(minutes, &scan.weather))
+        .collect();
+
+    ScanData {
+        threat_level: calculate_threat_level(&active, &scan.weather),
+        monsters: active.into_iter().cloned().collect(),
+        ..scan.clone()
+    }
+}
+
+/// Marks the ship as landed at the start of the day's shift.
+pub fn land(game_state: &mut GameState) {
+    game_state.landed_time = Some(LANDING_TIME);
+}
+
+/// Advances the landed clock. Returns true if midnight was reached, in which
+/// case the ship has already departed for the Company and the day has ended.
+/// Anything still inside a facility at that point is lost.
+pub fn pass_time(game_state: &mut GameState, minutes: u32) -> bool {
+    let Some(now) = game_state.landed_time else {
+        return false;
+    };
+
+    let later = now + minutes;
+    if later < MIDNIGHT {
+        game_state.landed_time = Some(later);
+        return false;
+    }
+
+    game_state.landed_time = None;
+    game_state.facility = None;
+    game_state.encounter = None;
+    game_state.ship.location = "COMPANY".to_string();
+    game_state.advance_day();
+    true
+}
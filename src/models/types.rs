@@ -1,4 +1,8 @@
 use crate::derive_struct;
+use crate::models::encounter::Encounter;
+use crate::models::facility::Facility;
+use crate::models::ledger::LedgerEntry;
+use crate::models::store::CartLine;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,10 +13,34 @@ pub struct GameState {
     pub players: Vec<Player>,
     pub ship: Ship,
     pub turn_number: u32,
+    #[serde(default = "first_day")]
+    pub day: u32,
     pub is_game_over: bool,
     pub scan_data: HashMap<String, ScanData>,
+    #[serde(default)]
+    pub discovered_creatures: Vec<String>,
+    #[serde(default)]
+    pub landed_time: Option<u32>,
+    #[serde(default)]
+    pub facility: Option<Facility>,
+    #[serde(default)]
+    pub encounter: Option<Encounter>,
+    #[serde(default)]
+    pub cart: Vec<CartLine>,
+    /// Seeds everything that should be the same for a given save, like the
+    /// store's daily sales.
+    #[serde(default)]
+    pub seed: u64,
+    #[serde(default)]
+    pub quota: Quota,
+    #[serde(default)]
+    pub ledger: Vec<LedgerEntry>,
 }}
 
+fn first_day() -> u32 {
+    1
+}
+
 impl Default for GameState {
     fn default() -> Self {
         GameState {
@@ -23,14 +51,69 @@ impl Default for GameState {
                 number_operators_alive: 0,
                 upgrades: vec![],
                 decorations: vec![],
+                scrap: vec![],
+                storage: vec![],
             },
             turn_number: 1,
+            day: 1,
             is_game_over: false,
             scan_data: HashMap::new(),
+            discovered_creatures: vec![],
+            landed_time: None,
+            facility: None,
+            encounter: None,
+            cart: vec![],
+            seed: 0,
+            quota: Quota::default(),
+            ledger: vec![],
         }
     }
 }
 
+impl GameState {
+    /// Scrap the operator has grabbed inside the facility and not yet
+    /// brought aboard.
+    pub fn carried_scrap(&self) -> &[Scrap] {
+        self.facility.as_ref().map_or(&[], |f| &f.carried)
+    }
+
+    /// Ends the current day, drops every scan that was generated before it
+    /// and starts the next quota once the deadline has passed.
+    pub fn advance_day(&mut self) {
+        self.day += 1;
+        let day = self.day;
+        self.scan_data.retain(|_, scan| scan.day >= day);
+        self.quota.roll_over(day);
+    }
+
+    /// Stores a fresh scan of `location`. A moon stays picked clean for the
+    /// rest of the day, however often it is rescanned; the new readings are
+    /// unsurveyed until the next COLLECT.
+    pub fn store_scan(&mut self, location: &str, mut scan: ScanData) {
+        if let Some(previous) = self.scan_data.get(location)
+            && previous.day == scan.day
+        {
+            scan.depleted |= previous.depleted;
+        }
+        self.scan_data.insert(location.to_string(), scan);
+    }
+
+    pub fn has_discovered(&self, creature: &str) -> bool {
+        self.discovered_creatures
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(creature))
+    }
+
+    /// Records a creature as identified. Returns true the first time it is seen.
+    pub fn discover_creature(&mut self, creature: &str) -> bool {
+        if self.has_discovered(creature) {
+            return false;
+        }
+        self.discovered_creatures.push(creature.to_string());
+        true
+    }
+}
+
 derive_struct! {
 pub struct Player {
     pub name: String,
@@ -46,6 +129,20 @@ pub struct Ship {
     pub number_operators_alive: u32,
     pub upgrades: Vec<String>,
     pub decorations: Vec<String>,
+    /// Scrap brought back aboard, waiting to be sold at the Company.
+    #[serde(default)]
+    pub scrap: Vec<Scrap>,
+    /// Purchases that didn't fit in the operator's slots, waiting to be
+    /// TAKEn.
+    #[serde(default)]
+    pub storage: Vec<Item>,
+}}
+
+derive_struct! {
+pub struct Scrap {
+    pub name: String,
+    pub value: u32,
+    pub weight: f32,
 }}
 
 derive_struct! {
@@ -54,8 +151,31 @@ pub struct Item {
     pub price: u32,
     pub weight: f32,
     pub description: String,
+    /// Hits left before a tool breaks, for items that wear out.
+    #[serde(default)]
+    pub uses_remaining: Option<u32>,
+    /// Battery charge in percent, for items that run on batteries.
+    #[serde(default)]
+    pub charge: Option<u32>,
+    /// Charge lost per landed action while the item is equipped.
+    #[serde(default)]
+    pub battery_drain: u32,
+    /// Used up the first time it is used.
+    #[serde(default)]
+    pub consumable: bool,
+    /// Where the item goes when equipped; `None` for items that can't be.
+    #[serde(default)]
+    pub slot: Option<EquipSlot>,
+    #[serde(default)]
+    pub equipped: bool,
 }}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EquipSlot {
+    Hand,
+    Belt,
+}
+
 derive_struct! {
 pub struct Monster {
     pub name: String,
@@ -67,6 +187,12 @@ pub struct Monster {
     pub moons: Vec<String>,
     pub speed: Option<String>,
     pub notes: Option<String>,
+    /// Spawn weight on every moon the creature appears on.
+    #[serde(default)]
+    pub rarity: Option<u32>,
+    /// Per-moon weights that override `rarity`, keyed by moon name.
+    #[serde(default)]
+    pub spawn_weights: HashMap<String, u32>,
 }}
 
 derive_struct! {
@@ -85,6 +211,67 @@ impl Default for Bestiary {
     }
 }
 
+impl Bestiary {
+    /// The compiled-in creatures used to seed the database.
+    pub fn default_content() -> Self {
+        Bestiary {
+            id: Some("bestiary".to_string()),
+            monsters: crate::models::lists::BESTIARY.to_vec(),
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Monster> {
+        self.monsters.iter().find(|m| m.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Gives creatures saved before spawn weights existed the compiled-in
+    /// `rarity` and `spawn_weights` of the creature with the same name.
+    /// Returns true if anything was filled in.
+    pub fn fill_spawn_defaults(&mut self) -> bool {
+        let defaults = Bestiary::default_content();
+        let mut changed = false;
+        for monster in &mut self.monsters {
+            if monster.rarity.is_some() || !monster.spawn_weights.is_empty() {
+                continue;
+            }
+            if let Some(default) = defaults.find(&monster.name)
+                && (default.rarity.is_some() || !default.spawn_weights.is_empty())
+            {
+                monster.rarity = default.rarity;
+                monster.spawn_weights = default.spawn_weights.clone();
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Entries whose names appear in `discovered`, in bestiary order.
+    pub fn discovered(&self, discovered: &[String]) -> Vec<&Monster> {
+        self.monsters
+            .iter()
+            .filter(|m| discovered.iter().any(|name| name.eq_ignore_ascii_case(&m.name)))
+            .collect()
+    }
+}
+
+derive_struct! {
+pub struct Catalog {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub moons: Vec<String>,
+    pub store_items: Vec<Item>,
+}}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Catalog {
+            id: Some("catalog".to_string()),
+            moons: crate::models::lists::MOONS.iter().map(|m| m.to_string()).collect(),
+            store_items: crate::models::lists::STORE_ITEMS.to_vec(),
+        }
+    }
+}
+
 derive_struct! {
     pub struct ScanData {
         pub weather: String,
@@ -92,6 +279,14 @@ derive_struct! {
         pub scrap_value: u32,
         #[serde(default)]
         pub monsters: Vec<Monster>,
+        #[serde(default)]
+        pub day: u32,
+        #[serde(default)]
+        pub depleted: bool,
+        #[serde(default)]
+        pub seed: u64,
+        #[serde(default)]
+        pub revealed: bool,
     }
 }
 
@@ -119,3 +314,89 @@ impl Default for CollectConfig {
         }
     }
 }
+
+derive_struct! {
+/// The Company's profit quota: scrap sold counts towards `target`, which is
+/// due by the end of `deadline_day`.
+pub struct Quota {
+    pub target: u32,
+    pub fulfilled: u32,
+    pub deadline_day: u32,
+}}
+
+impl Default for Quota {
+    fn default() -> Self {
+        Quota {
+            target: 130,
+            fulfilled: 0,
+            deadline_day: 4,
+        }
+    }
+}
+
+/// Days the crew gets for each quota, counting the deadline day.
+pub const QUOTA_DAYS: u32 = 4;
+/// Percent the target grows by with every new quota.
+pub const QUOTA_GROWTH: u32 = 50;
+
+impl Quota {
+    /// Starts the next quota once `day` is past the deadline: nothing sold
+    /// counts yet, the target grows by `QUOTA_GROWTH` percent and the
+    /// deadline moves `QUOTA_DAYS` on. Returns true if it did.
+    pub fn roll_over(&mut self, day: u32) -> bool {
+        if day <= self.deadline_day {
+            return false;
+        }
+        while day > self.deadline_day {
+            self.deadline_day += QUOTA_DAYS;
+        }
+        self.target = self.target * (100 + QUOTA_GROWTH) / 100;
+        self.fulfilled = 0;
+        true
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.fulfilled >= self.target
+    }
+
+    /// How far along the quota is, from 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        if self.target == 0 {
+            return 1.0;
+        }
+        (self.fulfilled as f32 / self.target as f32).min(1.0)
+    }
+}
+
+derive_struct! {
+    pub struct StoreConfig {
+        #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+    /// Most items that can be on sale on the same day.
+    pub max_daily_sales: u32,
+    pub min_discount: u32,
+    pub max_discount: u32,
+    /// Percent added to every price once the quota is fully met; scaled down
+    /// linearly with quota progress.
+    pub quota_markup: u32,
+    /// Percent of the price paid that the Company gives back for an item.
+    #[serde(default = "default_refund_percent")]
+    pub refund_percent: u32,
+}}
+
+fn default_refund_percent() -> u32 {
+    50
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        StoreConfig {
+            id: Some("store_config".to_string()),
+            max_daily_sales: 3,
+            min_discount: 10,
+            max_discount: 80,
+            quota_markup: 20,
+            refund_percent: default_refund_percent(),
+        }
+    }
+}
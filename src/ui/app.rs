@@ -1,12 +1,23 @@
+use crate::data::mods::ModReport;
+use crate::models::game_data::GameData;
+use crate::models::store::DailyPrices;
+use crate::ui::effects::{effects_enabled, BootScreen, Reveal, Typewriter};
+use crate::ui::tasks::Tasks;
+use crate::ui::theme::{no_color, Theme, ThemeSet};
+use crate::ui::log::{scrollback_limit, LogEntry, LogKind};
+use crate::ui::search::Search;
 use crate::models::types::GameState;
 use std::sync::Arc;
 use mongodb::Client;
+use ratatui::layout::Rect;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum InputMode {
     Normal,
     Confirmation,
     Editing,
+    /// Typing a `/` query or stepping through its matches.
+    Search,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -15,44 +26,149 @@ pub enum ConfirmationType {
     Quit,
     AcceptTerms,
     Collect,
+    Checkout,
+    /// Registering a new operator: asking for their name.
+    OperatorName,
+    /// Asking for the role of the operator with this name.
+    OperatorRole(String),
+}
+
+/// The side panels next to the command log.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Panel {
+    Status,
+    Scan,
+    Inventory,
+}
+
+impl Panel {
+    pub const ALL: [Panel; 3] = [Panel::Status, Panel::Scan, Panel::Inventory];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Panel::Status => " Crew [F2] ",
+            Panel::Scan => " Scan [F3] ",
+            Panel::Inventory => " Inventory [F4] ",
+        }
+    }
+}
+
+/// Which side panels the player wants to see. They are still hidden when
+/// the terminal is too narrow for them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Panels {
+    pub status: bool,
+    pub scan: bool,
+    pub inventory: bool,
+}
+
+impl Default for Panels {
+    fn default() -> Self {
+        Panels {
+            status: true,
+            scan: true,
+            inventory: true,
+        }
+    }
+}
+
+impl Panels {
+    pub fn is_visible(&self, panel: Panel) -> bool {
+        match panel {
+            Panel::Status => self.status,
+            Panel::Scan => self.scan,
+            Panel::Inventory => self.inventory,
+        }
+    }
+
+    pub fn toggle(&mut self, panel: Panel) {
+        match panel {
+            Panel::Status => self.status = !self.status,
+            Panel::Scan => self.scan = !self.scan,
+            Panel::Inventory => self.inventory = !self.inventory,
+        }
+    }
+
+    pub fn visible(&self) -> Vec<Panel> {
+        Panel::ALL.into_iter().filter(|p| self.is_visible(*p)).collect()
+    }
 }
 
 pub struct App {
     pub game_state: GameState,
+    pub game_data: GameData,
+    pub mod_report: ModReport,
     pub db_client: Arc<Client>,
     pub input: String,
-    pub message_log: Vec<String>,
+    pub message_log: Vec<LogEntry>,
+    /// Only entries of this kind are shown when set.
+    pub log_filter: Option<LogKind>,
     pub input_mode: InputMode,
     pub confirmation_type: Option<ConfirmationType>,
     pub should_quit: bool,
     pub cursor_position: usize,
     pub is_processing: bool,
+    /// Command running in the background, e.g. a save.
+    pub tasks: Tasks,
+    /// Ticks since start-up; drives animations.
+    pub ticks: u64,
+    /// Last known terminal size as (columns, rows).
+    pub terminal_size: (u16, u16),
+    pub theme: Theme,
+    pub typewriter: Typewriter,
+    /// Start-up animation; the terminal shows once it is gone.
+    pub boot: Option<BootScreen>,
+    /// Log lines picked with the mouse, as (anchor, cursor) indices into
+    /// `components::log_lines`.
+    pub selection: Option<(usize, usize)>,
+    pub search: Option<Search>,
+    /// Most log entries kept before the oldest are dropped.
+    pub scrollback: usize,
+    /// Entries dropped so far to stay within `scrollback`.
+    pub dropped_entries: usize,
+    pub themes: ThemeSet,
     pub scroll_offset: usize,
     pub auto_scroll: bool,
+    pub panels: Panels,
 }
 
 impl App {
-    pub fn new(game_state: GameState, db_client: Arc<Client>) -> Self {
+    pub fn new(game_state: GameState, game_data: GameData, db_client: Arc<Client>) -> Self {
         let mut app = Self {
             game_state,
+            game_data,
+            mod_report: ModReport::default(),
             db_client,
             input: String::new(),
             message_log: Vec::new(),
+            log_filter: None,
             input_mode: InputMode::Normal,
             confirmation_type: None,
             should_quit: false,
             cursor_position: 0,
             is_processing: false,
+            tasks: Tasks::default(),
+            ticks: 0,
+            terminal_size: (0, 0),
+            theme: ThemeSet::default().startup(no_color()),
+            themes: ThemeSet::default(),
+            typewriter: Typewriter::new(effects_enabled()),
+            boot: effects_enabled().then(BootScreen::default),
+            selection: None,
+            search: None,
+            scrollback: scrollback_limit(),
+            dropped_entries: 0,
             scroll_offset: 0,
             auto_scroll: true,
+            panels: Panels::default(),
         };
         
         // Initial welcome messages
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        app.add_message("🚀 Terminal Company OS v0.1.0");
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        app.add_separator();
+        app.add_heading("🚀 Terminal Company OS v0.1.0");
+        app.add_separator();
         app.add_message("");
-        app.add_message(&format!("Welcome, Operator {}!", app.game_state.players[0].name));
+        app.add_heading(&format!("Welcome, Operator {}!", app.game_state.players[0].name));
         app.add_message(&format!("Current Location: {}", app.game_state.ship.location));
         app.add_message(&format!("Credits: {}", app.game_state.players[0].credits));
         app.add_message("");
@@ -63,17 +179,178 @@ impl App {
     }
     
     pub fn add_message(&mut self, message: &str) {
-        self.message_log.push(message.to_string());
+        self.push_log(LogEntry::new(LogKind::Info, message));
+    }
+    
+    pub fn add_command(&mut self, command: &str) {
+        self.push_log(LogEntry::new(LogKind::Command, &format!("> {}", command)));
+    }
+    
+    pub fn add_heading(&mut self, message: &str) {
+        self.push_log(LogEntry::new(LogKind::Heading, message));
+    }
+    
+    pub fn add_warning(&mut self, message: &str) {
+        self.push_log(LogEntry::new(LogKind::Warning, message));
+    }
+    
+    pub fn add_error(&mut self, message: &str) {
+        self.push_log(LogEntry::new(LogKind::Error, message));
+    }
+    
+    pub fn add_success(&mut self, message: &str) {
+        self.push_log(LogEntry::new(LogKind::Success, message));
+    }
+    
+    pub fn add_separator(&mut self) {
+        self.push_log(LogEntry::new(LogKind::Separator, "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━"));
+    }
+    
+    /// Adds a line that fills the input with `command` when clicked.
+    pub fn add_action(&mut self, message: &str, command: &str) {
+        self.push_log(LogEntry::new(LogKind::Info, message).with_action(command));
+    }
+    
+    /// Adds a table that is laid out to the width of the log pane when drawn.
+    pub fn add_table(&mut self, headers: &[&str], rows: Vec<Vec<String>>) {
+        self.push_log(LogEntry::table(headers, rows));
+    }
+    
+    pub fn push_log(&mut self, entry: LogEntry) {
+        self.message_log.push(entry);
         
-        if self.message_log.len() > 1000 {
-            self.message_log.drain(0..100);
-        }
+        self.trim_log();
         
         if self.auto_scroll {
             self.scroll_offset = 0;
         }
     }
     
+    /// Changes the scrollback limit, dropping old entries if needed.
+    pub fn set_scrollback(&mut self, limit: usize) {
+        self.scrollback = limit;
+        self.trim_log();
+    }
+    
+    fn trim_log(&mut self) {
+        let excess = self.message_log.len().saturating_sub(self.scrollback);
+        if excess > 0 {
+            self.message_log.drain(0..excess);
+            self.typewriter.dropped(excess);
+            self.dropped_entries += excess;
+        }
+    }
+    
+    /// Number of log lines as drawn and the height of the log pane, for the
+    /// current terminal size.
+    pub fn log_extent(&self) -> (usize, usize) {
+        use crate::ui::components::{log_lines, screen_layout};
+        
+        let layout = screen_layout(self.screen(), &self.panels);
+        let total = log_lines(self, layout.log.width.saturating_sub(2) as usize).len();
+        (total, layout.log.height.saturating_sub(2) as usize)
+    }
+    
+    /// The search text to highlight, while typing it or browsing matches.
+    pub fn search_query(&self) -> Option<&str> {
+        let search = self.search.as_ref()?;
+        let query = if search.editing { self.input.as_str() } else { search.query.as_str() };
+        (!query.is_empty()).then_some(query)
+    }
+    
+    /// Log entries that pass the current filter, oldest first, with how
+    /// much of each the typewriter has revealed so far.
+    pub fn visible_log(&self) -> Vec<(usize, &LogEntry, Reveal)> {
+        self.message_log
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (i, e, self.typewriter.reveal(i)))
+            .filter(|(_, e, reveal)| *reveal != Reveal::Hidden && self.log_filter.is_none_or(|kind| e.kind == kind))
+            .collect()
+    }
+    
+    /// Turns the boot screen and typewriter on or off.
+    pub fn set_effects(&mut self, enabled: bool) {
+        self.typewriter.set_enabled(enabled, &self.message_log);
+        if !enabled {
+            self.boot = None;
+        }
+    }
+    
+    /// Skips any running animation. Returns true if the boot screen was up.
+    pub fn skip_effects(&mut self) -> bool {
+        self.typewriter.finish(&self.message_log);
+        self.boot.take().is_some()
+    }
+    
+    /// Writes the whole log as plain text. Returns the number of entries.
+    pub fn export_log(&self, path: &std::path::Path) -> std::io::Result<usize> {
+        let text: Vec<String> = self.message_log.iter().map(LogEntry::export).collect();
+        std::fs::write(path, text.join("\n") + "\n")?;
+        Ok(self.message_log.len())
+    }
+    
+    pub fn on_tick(&mut self) {
+        self.ticks = self.ticks.wrapping_add(1);
+        
+        match self.boot.as_mut() {
+            Some(boot) if boot.is_done() => self.boot = None,
+            Some(boot) => boot.tick(),
+            None => self.typewriter.tick(&self.message_log),
+        }
+    }
+    
+    pub fn on_resize(&mut self, width: u16, height: u16) {
+        let rewrapped = self.terminal_size.0 != width;
+        self.terminal_size = (width, height);
+        // Tables are laid out to the width, so an old scroll offset would
+        // point somewhere else; jump back to the newest output instead.
+        if rewrapped {
+            self.scroll_to_bottom();
+        }
+    }
+    
+    /// Today's store prices for the current save.
+    pub fn prices(&self) -> DailyPrices {
+        DailyPrices::new(
+            self.game_state.seed,
+            self.game_state.day,
+            &self.game_state.quota,
+            &self.game_data.store_items,
+            &self.game_data.store_config,
+        )
+    }
+    
+    pub fn set_mod_report(&mut self, report: ModReport) {
+        if !report.packs.is_empty() {
+            self.add_message(&format!("🧩 {} mod pack(s) active. Type MODS for details.", report.packs.len()));
+        }
+        if !report.errors.is_empty() {
+            self.add_warning(&format!("⚠️ {} mod problem(s) found. Type MODS for details.", report.errors.len()));
+        }
+        self.mod_report = report;
+    }
+    
+    /// Installs the themes loaded at startup and switches to their default.
+    pub fn set_themes(&mut self, themes: ThemeSet) {
+        for error in &themes.errors {
+            self.add_warning(&format!("⚠️ Theme problem: {}", error));
+        }
+        self.theme = themes.startup(no_color());
+        self.themes = themes;
+    }
+    
+    /// Replaces the input line, e.g. with a clicked command.
+    pub fn set_input(&mut self, text: &str) {
+        self.input = text.to_string();
+        self.cursor_position = self.input.len();
+    }
+    
+    /// The whole terminal, as last reported by a resize.
+    pub fn screen(&self) -> Rect {
+        Rect::new(0, 0, self.terminal_size.0, self.terminal_size.1)
+    }
+    
     pub fn clear_input(&mut self) {
         self.input.clear();
         self.cursor_position = 0;
@@ -121,12 +398,19 @@ impl App {
                         ConfirmationType::Quit => "Quit game? (YES/NO) > ".to_string(),
                         ConfirmationType::AcceptTerms => "Accept terms? (ACCEPT/DENY) > ".to_string(),
                         ConfirmationType::Collect => "Attempt collecting? (YES/NO) > ".to_string(),
+                        ConfirmationType::Checkout => "Confirm purchase? (YES/NO) > ".to_string(),
+                        ConfirmationType::OperatorName => "Name > ".to_string(),
+                        ConfirmationType::OperatorRole(_) => "Role > ".to_string(),
                     }
                 } else {
                     "(YES/NO) > ".to_string()
                 }
             }
             InputMode::Editing => "Enter value > ".to_string(),
+            InputMode::Search => match &self.search {
+                Some(search) if !search.editing => format!("/{} ({}/{}) > ", search.query, search.position, search.total),
+                _ => "/".to_string(),
+            },
         }
     }
     
@@ -138,7 +422,8 @@ impl App {
     }
     
     pub fn scroll_up(&mut self, lines: usize) {
-        let max_scroll = self.message_log.len().saturating_sub(1);
+        let (total, height) = self.log_extent();
+        let max_scroll = total.saturating_sub(height);
         self.scroll_offset = (self.scroll_offset + lines).min(max_scroll);
         self.auto_scroll = false;
     }
@@ -160,12 +445,112 @@ impl App {
     /// info to display
     pub fn get_status_info(&self) -> Vec<(String, String)> {
         let player = &self.game_state.players[0];
-        vec![
+        let mut info = vec![
             ("Operator".to_string(), player.name.clone()),
             ("Credits".to_string(), format!("💰 {}", player.credits)),
             ("Location".to_string(), format!("📍 {}", self.game_state.ship.location)),
             ("Turn".to_string(), format!("#{}", self.game_state.turn_number)),
+            ("Day".to_string(), format!("{}", self.game_state.day)),
             ("HP".to_string(), format!("❤️  {}/100", player.hp)),
-        ]
+        ];
+        if let Some(now) = self.game_state.landed_time {
+            info.push(("Time".to_string(), format!("🕒 {}", crate::models::time_of_day::format_clock(now))));
+        }
+        info
+    }
+    
+    /// Lines for the crew/status panel.
+    pub fn status_panel(&self) -> Vec<String> {
+        use crate::models::time_of_day::format_clock;
+        
+        let player = &self.game_state.players[0];
+        let quota = &self.game_state.quota;
+        let mut lines = vec![
+            format!("{} ({})", player.name, player.role),
+            format!("HP       {}/100", player.hp),
+            format!("Credits  {}", player.credits),
+            format!("Quota    {}/{}", quota.fulfilled, quota.target),
+            format!("Deadline day {}", quota.deadline_day),
+            format!("Day {}  {}", self.game_state.day, self.game_state.landed_time.map(format_clock).unwrap_or_default()),
+            format!("📍 {}", self.game_state.ship.location),
+        ];
+        if let Some(facility) = &self.game_state.facility {
+            lines.push(format!("🚪 {}", facility.current().name));
+        }
+        if let Some(encounter) = &self.game_state.encounter {
+            lines.push(format!("🚨 {} engaged!", encounter.monster.name));
+        }
+        lines
+    }
+    
+    /// Lines for the scan panel: what the crew knows about the current moon,
+    /// with the same uncertainty the SCAN command shows.
+    pub fn scan_panel(&self) -> Vec<String> {
+        use crate::models::scan_logic::is_scan_expired;
+        use crate::models::scan_report::{build_report, scan_confidence, Sighting};
+        use crate::models::time_of_day::{scan_at_time, LANDING_TIME};
+        
+        let location = &self.game_state.ship.location;
+        let scan = match self.game_state.scan_data.get(location) {
+            Some(scan) if !is_scan_expired(scan, self.game_state.day) => scan,
+            _ => return vec!["No scan for this location.".to_string()],
+        };
+        
+        let now = self.game_state.landed_time.unwrap_or(LANDING_TIME);
+        let confidence = scan_confidence(&self.game_state.players[0], &self.game_state.ship);
+        let report = build_report(&scan_at_time(scan, now), confidence, &self.game_state.discovered_creatures);
+        
+        let range = |(low, high): (u32, u32)| {
+            if low == high { low.to_string() } else { format!("{}-{}", low, high) }
+        };
+        let mut lines = vec![
+            format!("Weather  {}", report.weather),
+            format!("Threat   {}%", range(report.threat_range)),
+            format!("Scrap    {} cr", range(report.scrap_range)),
+            format!("Signal   {}%", report.confidence),
+        ];
+        if scan.depleted {
+            lines.push("Picked clean".to_string());
+        }
+        for sighting in &report.sightings {
+            lines.push(match sighting {
+                Sighting::Identified { name, .. } => format!("- {}", name),
+                Sighting::UnknownSignature => "- ???".to_string(),
+            });
+        }
+        lines
+    }
+    
+    /// Lines for the inventory panel.
+    pub fn inventory_panel(&self) -> Vec<String> {
+        use crate::models::items::{capacity, load_weight, slots_used, slotted, weight_penalty};
+        
+        let player = &self.game_state.players[0];
+        let weight = load_weight(player, self.game_state.carried_scrap());
+        let mut lines = vec![format!(
+            "{}/{} slots  {} lb  -{}%",
+            slots_used(player),
+            capacity(player),
+            weight,
+            weight_penalty(weight)
+        )];
+        for (i, item) in slotted(player).enumerate() {
+            let mut line = format!("{}. {}", i + 1, item.name);
+            if item.equipped {
+                line.push_str(" *");
+            }
+            if let Some(charge) = item.charge {
+                line.push_str(&format!(" {}%", charge));
+            }
+            lines.push(line);
+        }
+        if let Some(facility) = &self.game_state.facility {
+            lines.push(format!("Carrying {} cr of scrap", facility.carried_value()));
+        }
+        let ship_scrap: u32 = self.game_state.ship.scrap.iter().map(|s| s.value).sum();
+        if ship_scrap > 0 {
+            lines.push(format!("Ship scrap {} cr", ship_scrap));
+        }
+        lines
     }
 }
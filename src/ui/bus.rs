@@ -0,0 +1,129 @@
+use crate::ui::log::{LogEntry, LogKind};
+use crate::ui::tasks::TaskDone;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// Something the TUI should show, sent by code that has no `&mut App`.
+#[derive(Debug, Clone)]
+pub enum UiEvent {
+    Log(LogEntry),
+    TaskDone(TaskDone),
+}
+
+/// Sending half of the bus. Cheap to clone and safe to move into threads or
+/// tokio tasks.
+#[derive(Debug, Clone)]
+pub struct Publisher {
+    sender: Sender<UiEvent>,
+    wake: Arc<Notify>,
+}
+
+impl Publisher {
+    pub fn send(&self, event: UiEvent) {
+        // Nobody is listening once the UI has shut down; dropping the event
+        // is the right thing then.
+        if self.sender.send(event).is_ok() {
+            self.wake.notify_one();
+        }
+    }
+
+    pub fn log(&self, kind: LogKind, text: &str) {
+        self.send(UiEvent::Log(LogEntry::new(kind, text)));
+    }
+
+    pub fn info(&self, text: &str) {
+        self.log(LogKind::Info, text);
+    }
+
+    pub fn warning(&self, text: &str) {
+        self.log(LogKind::Warning, text);
+    }
+
+    pub fn error(&self, text: &str) {
+        self.log(LogKind::Error, text);
+    }
+
+    pub fn success(&self, text: &str) {
+        self.log(LogKind::Success, text);
+    }
+}
+
+pub struct EventBus {
+    publisher: Publisher,
+    receiver: Mutex<Receiver<UiEvent>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, receiver) = channel();
+        EventBus {
+            publisher: Publisher {
+                sender,
+                wake: Arc::new(Notify::new()),
+            },
+            receiver: Mutex::new(receiver),
+        }
+    }
+
+    /// Resolves once something has been published since the last wait, so
+    /// an async loop can sleep until there is work to drain.
+    pub async fn wait(&self) {
+        self.publisher.wake.notified().await;
+    }
+
+    pub fn publisher(&self) -> Publisher {
+        self.publisher.clone()
+    }
+
+    /// Everything published since the last drain, oldest first. Never blocks.
+    pub fn drain(&self) -> Vec<UiEvent> {
+        match self.receiver.lock() {
+            Ok(receiver) => receiver.try_iter().collect(),
+            Err(poisoned) => poisoned.into_inner().try_iter().collect(),
+        }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref BUS: EventBus = EventBus::new();
+}
+
+/// A publisher for the bus that `run_app` drains every tick.
+pub fn publisher() -> Publisher {
+    BUS.publisher()
+}
+
+pub fn drain() -> Vec<UiEvent> {
+    BUS.drain()
+}
+
+pub async fn wait() {
+    BUS.wait().await;
+}
+
+#[macro_export]
+macro_rules! ui_println {
+    () => {
+        $crate::ui::bus::publisher().info("")
+    };
+    ($($arg:tt)*) => {
+        $crate::ui::bus::publisher().info(&format!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! ui_eprintln {
+    () => {
+        $crate::ui::bus::publisher().warning("")
+    };
+    ($($arg:tt)*) => {
+        $crate::ui::bus::publisher().warning(&format!("⚠️ {}", format!($($arg)*)))
+    };
+}
@@ -0,0 +1,32 @@
+use std::io::{self, Write};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// The OSC 52 escape sequence asking the terminal to put `text` on the
+/// system clipboard.
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Copies `text` through the terminal, which also works over SSH. Terminals
+/// without OSC 52 support silently ignore it.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    stdout.write_all(osc52(text).as_bytes())?;
+    stdout.flush()
+}
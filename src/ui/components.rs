@@ -1,13 +1,52 @@
-use crate::ui::app::App;
+use crate::ui::app::{App, Panel, Panels};
+use crate::ui::effects::{BootScreen, Reveal};
+use crate::ui::tasks::spinner;
+use std::ops::Range;
+use crate::ui::log::LogKind;
+use crate::ui::search::match_ranges;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
 
 pub fn render(f: &mut Frame, app: &App) {
+    if let Some(boot) = &app.boot {
+        render_boot(f, f.area(), app, boot);
+        return;
+    }
+    
+    let layout = screen_layout(f.area(), &app.panels);
+    
+    render_header(f, layout.header, app);
+    render_messages(f, layout.log, app);
+    for (panel, area) in layout.panels {
+        render_panel(f, area, app, panel);
+    }
+    render_input(f, layout.input, app);
+    if let Some(tabs) = layout.tabs {
+        render_tabs(f, tabs, app);
+    }
+    render_footer(f, layout.footer, app);
+}
+
+/// Where each part of the screen goes. Shared by drawing and mouse hit
+/// testing so a click lands on what the player sees.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenLayout {
+    pub header: Rect,
+    pub log: Rect,
+    pub panels: Vec<(Panel, Rect)>,
+    pub input: Rect,
+    /// Clickable panel tabs at the left of the footer; only on terminals
+    /// wide enough for panels.
+    pub tabs: Option<Rect>,
+    pub footer: Rect,
+}
+
+pub fn screen_layout(area: Rect, panels: &Panels) -> ScreenLayout {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -16,12 +55,118 @@ pub fn render(f: &mut Frame, app: &App) {
             Constraint::Length(3),       // Input area
             Constraint::Length(1),       // Footer with help
         ])
-        .split(f.area());
+        .split(area);
+    
+    let (log, side_panels) = body_layout(chunks[1], panels);
+    let (tabs, footer) = if area.width >= MIN_WIDTH_FOR_PANELS {
+        let footer = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(tabs_width()), Constraint::Min(0)])
+            .split(chunks[3]);
+        (Some(footer[0]), footer[1])
+    } else {
+        (None, chunks[3])
+    };
+    
+    ScreenLayout {
+        header: chunks[0],
+        log,
+        panels: side_panels,
+        input: chunks[2],
+        tabs,
+        footer,
+    }
+}
 
-    render_header(f, chunks[0], app);
-    render_messages(f, chunks[1], app);
-    render_input(f, chunks[2], app);
-    render_footer(f, chunks[3], app);
+/// Terminals narrower than this show only the command log.
+pub const MIN_WIDTH_FOR_PANELS: u16 = 100;
+pub const SIDE_PANEL_WIDTH: u16 = 34;
+
+/// Splits the body between the command log and the visible side panels.
+/// The log always keeps the larger share; the panels are stacked on the
+/// right and dropped altogether when the terminal is too narrow.
+pub fn body_layout(area: Rect, panels: &Panels) -> (Rect, Vec<(Panel, Rect)>) {
+    let visible = panels.visible();
+    if visible.is_empty() || area.width < MIN_WIDTH_FOR_PANELS {
+        return (area, vec![]);
+    }
+    
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(SIDE_PANEL_WIDTH)])
+        .split(area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(visible.iter().map(|_| Constraint::Ratio(1, visible.len() as u32)))
+        .split(columns[1]);
+    
+    (columns[0], visible.into_iter().zip(rows.iter().copied()).collect())
+}
+
+fn tabs_width() -> u16 {
+    Panel::ALL.iter().map(|p| p.title().chars().count() as u16).sum()
+}
+
+/// The panel tab under `column`, if any.
+pub fn tab_at(tabs: Rect, column: u16) -> Option<Panel> {
+    let mut x = tabs.x;
+    for panel in Panel::ALL {
+        let width = panel.title().chars().count() as u16;
+        if (x..x + width).contains(&column) {
+            return Some(panel);
+        }
+        x += width;
+    }
+    None
+}
+
+fn render_tabs(f: &mut Frame, area: Rect, app: &App) {
+    let spans: Vec<Span> = Panel::ALL
+        .iter()
+        .map(|panel| {
+            let style = if app.panels.is_visible(*panel) { app.theme.accent } else { app.theme.muted };
+            Span::styled(panel.title(), style)
+        })
+        .collect();
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_panel(f: &mut Frame, area: Rect, app: &App, panel: Panel) {
+    let lines = match panel {
+        Panel::Status => app.status_panel(),
+        Panel::Scan => app.scan_panel(),
+        Panel::Inventory => app.inventory_panel(),
+    };
+    
+    let widget = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
+        .style(app.theme.text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(panel.title())
+                .border_style(app.theme.border)
+        );
+    
+    f.render_widget(widget, area);
+}
+
+fn render_boot(f: &mut Frame, area: Rect, app: &App, boot: &BootScreen) {
+    let mut lines: Vec<Line> = boot.visible_lines().iter().map(|l| Line::from(l.as_str())).collect();
+    if app.ticks % 6 < 3 {
+        lines.push(Line::from("█"));
+    }
+    
+    let screen = Paragraph::new(lines)
+        .style(app.theme.text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Terminal Company OS ")
+                .title_alignment(Alignment::Center)
+                .border_style(app.theme.border)
+        );
+    
+    f.render_widget(screen, area);
 }
 
 fn render_header(f: &mut Frame, area: Rect, app: &App) {
@@ -34,11 +179,11 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
         }
         spans.push(Span::styled(
             format!("{}: ", label),
-            Style::default().fg(Color::DarkGray),
+            app.theme.muted,
         ));
         spans.push(Span::styled(
             value,
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            app.theme.accent,
         ));
     }
     
@@ -48,71 +193,169 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
                 .borders(Borders::ALL)
                 .title(" Terminal Company ")
                 .title_alignment(Alignment::Center)
-                .border_style(Style::default().fg(Color::Green))
+                .border_style(app.theme.border)
         )
         .alignment(Alignment::Left);
     
     f.render_widget(header, area);
 }
 
-fn render_messages(f: &mut Frame, area: Rect, app: &App) {
-    let total_messages = app.message_log.len();
+/// One screen line of the log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    /// Index into `App::message_log`.
+    pub entry: usize,
+    pub kind: LogKind,
+    pub text: String,
+}
+
+/// The log as it is drawn in a pane `width` columns wide: filtered, with
+/// tables laid out and the typewriter applied.
+pub fn log_lines(app: &App, width: usize) -> Vec<LogLine> {
+    app.visible_log()
+        .into_iter()
+        .flat_map(|(index, entry, reveal)| {
+            let mut lines = entry.lines(width);
+            if let Reveal::Partial(mut chars) = reveal {
+                // Spread the typed characters over the wrapped lines in order.
+                lines = lines
+                    .into_iter()
+                    .map(|line| {
+                        let shown: String = line.chars().take(chars).collect();
+                        chars -= shown.chars().count();
+                        shown
+                    })
+                    .collect();
+            }
+            lines.into_iter().map(move |text| LogLine {
+                entry: index,
+                kind: entry.kind,
+                text,
+            })
+        })
+        .collect()
+}
+
+/// The range of lines that fit in `area`, `scroll_offset` lines up from
+/// the bottom.
+pub fn log_window(total_lines: usize, area: Rect, scroll_offset: usize) -> Range<usize> {
     let available_height = area.height.saturating_sub(2) as usize;
+    let scroll_offset = scroll_offset.min(total_lines.saturating_sub(available_height));
+    let end = total_lines.saturating_sub(scroll_offset);
+    end.saturating_sub(available_height)..end
+}
+
+/// The index in `log_lines` of the line drawn at (`column`, `row`).
+pub fn log_line_at(app: &App, area: Rect, column: u16, row: u16) -> Option<usize> {
+    let inside = column > area.x
+        && column < area.right().saturating_sub(1)
+        && row > area.y
+        && row < area.bottom().saturating_sub(1);
+    if !inside {
+        return None;
+    }
+    let total = log_lines(app, area.width.saturating_sub(2) as usize).len();
+    let window = log_window(total, area, app.scroll_offset);
+    let index = window.start + (row - area.y - 1) as usize;
+    window.contains(&index).then_some(index)
+}
+
+fn render_messages(f: &mut Frame, area: Rect, app: &App) {
+    let lines = log_lines(app, area.width.saturating_sub(2) as usize);
+    let window = log_window(lines.len(), area, app.scroll_offset);
+    let scroll_offset = lines.len() - window.end;
+    let selected = app.selection.map(|(a, b)| a.min(b)..=a.max(b));
     
-    let end_idx = total_messages.saturating_sub(app.scroll_offset);
-    let start_idx = end_idx.saturating_sub(available_height);
+    let query = app.search_query();
+    let current = app.search.as_ref().and_then(|search| search.current);
     
-    let messages: Vec<ListItem> = app.message_log[start_idx..end_idx]
+    let messages: Vec<ListItem> = lines[window.clone()]
         .iter()
-        .map(|msg| {
-            let style = if msg.contains("⚠️") || msg.contains("Error") || msg.contains("failed") {
-                Style::default().fg(Color::Red)
-            } else if msg.contains("✨") || msg.contains("Success") {
-                Style::default().fg(Color::Green)
-            } else if msg.contains("━━━") {
-                Style::default().fg(Color::DarkGray)
-            } else if msg.starts_with("🚀") || msg.starts_with("Welcome") {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        .zip(window.clone())
+        .map(|(line, index)| {
+            let mut style = app.theme.log_style(line.kind);
+            if selected.as_ref().is_some_and(|range| range.contains(&index)) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            let highlight = if current == Some(index) {
+                app.theme.heading.add_modifier(Modifier::REVERSED)
             } else {
-                Style::default().fg(Color::White)
+                style.add_modifier(Modifier::REVERSED)
             };
-            
-            ListItem::new(Line::from(msg.clone())).style(style)
+            ListItem::new(highlighted(&line.text, query, style, highlight))
         })
         .collect();
     
-    let title = if app.scroll_offset > 0 {
-        format!(" Output (↑ {} messages above) ", app.scroll_offset)
+    let mut title = if scroll_offset > 0 {
+        format!(" Output (↑ {} lines above) ", scroll_offset)
     } else if !app.auto_scroll {
         " Output (at bottom) ".to_string()
     } else {
         " Output ".to_string()
     };
+    if let Some(kind) = app.log_filter {
+        title = format!("{}[{} only] ", title, kind);
+    }
 
     let messages_list = List::new(messages)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(Color::White))
+                .border_style(app.theme.text)
         );
 
     f.render_widget(messages_list, area);
+    
+    let height = area.height.saturating_sub(2) as usize;
+    if lines.len() > height {
+        let mut state = ScrollbarState::new(lines.len() - height + 1)
+            .position(window.start)
+            .viewport_content_length(height);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight).style(app.theme.muted),
+            area.inner(Margin { vertical: 1, horizontal: 0 }),
+            &mut state,
+        );
+    }
+}
+
+/// `text` with every match of `query` drawn in `highlight`.
+fn highlighted<'a>(text: &'a str, query: Option<&str>, style: Style, highlight: Style) -> Line<'a> {
+    let ranges = query.map(|q| match_ranges(text, q)).unwrap_or_default();
+    let mut spans = vec![];
+    let mut at = 0;
+    for range in ranges {
+        spans.push(Span::styled(&text[at..range.start], style));
+        spans.push(Span::styled(&text[range.clone()], highlight));
+        at = range.end;
+    }
+    spans.push(Span::styled(&text[at..], style));
+    Line::from(spans)
 }
 
 fn render_input(f: &mut Frame, area: Rect, app: &App) {
     let prompt = app.get_prompt();
     
-    let input_text = if app.is_processing {
+    let input_text = if let Some(task) = app.tasks.pending() {
+        format!(
+            "{}{} {}... {:.1}s (Esc to cancel)",
+            prompt,
+            spinner(task.elapsed()),
+            task.label,
+            task.elapsed().as_secs_f32()
+        )
+    } else if app.is_processing {
         format!("{}⏳ Processing...", prompt)
     } else {
         format!("{}{}", prompt, app.input)
     };
     
     let input_style = match app.input_mode {
-        crate::ui::app::InputMode::Normal => Style::default().fg(Color::White),
-        crate::ui::app::InputMode::Confirmation => Style::default().fg(Color::Yellow),
-        crate::ui::app::InputMode::Editing => Style::default().fg(Color::Cyan),
+        crate::ui::app::InputMode::Normal => app.theme.text,
+        crate::ui::app::InputMode::Confirmation => app.theme.warning,
+        crate::ui::app::InputMode::Editing => app.theme.command,
+        crate::ui::app::InputMode::Search => app.theme.accent,
     };
     
     let input = Paragraph::new(input_text)
@@ -123,9 +366,9 @@ fn render_input(f: &mut Frame, area: Rect, app: &App) {
                 .title(" Command ")
                 .border_style(
                     if app.is_processing {
-                        Style::default().fg(Color::Yellow)
+                        app.theme.warning
                     } else {
-                        Style::default().fg(Color::Green)
+                        app.theme.border
                     }
                 )
         );
@@ -142,8 +385,11 @@ fn render_input(f: &mut Frame, area: Rect, app: &App) {
 
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     let help_text = match app.input_mode {
+        _ if app.tasks.is_busy() => {
+            "ESC: cancel | PgUp/PgDn/Mouse: scroll | Ctrl+C: force quit"
+        }
         crate::ui::app::InputMode::Normal => {
-            "ESC: quit | ENTER: submit | PgUp/PgDn/Mouse: scroll | Ctrl+C: force quit"
+            "ESC: quit | ENTER: submit | PgUp/PgDn/wheel: scroll | click: pick | drag: copy | F2-F4: panels | Ctrl+C: force quit"
         }
         crate::ui::app::InputMode::Confirmation => {
             "Type YES or NO, then press ENTER"
@@ -151,10 +397,16 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
         crate::ui::app::InputMode::Editing => {
             "Type your input, then press ENTER"
         }
+        crate::ui::app::InputMode::Search if app.search.as_ref().is_some_and(|s| s.editing) => {
+            "Type text to find, then press ENTER | ESC: cancel"
+        }
+        crate::ui::app::InputMode::Search => {
+            "n: older match | N: newer match | /: new search | ESC: close"
+        }
     };
     
     let footer = Paragraph::new(help_text)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(app.theme.muted)
         .alignment(Alignment::Center);
     
     f.render_widget(footer, area);
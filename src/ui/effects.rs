@@ -0,0 +1,149 @@
+use crate::commands::registration::BOOT_DOCUMENTS;
+use crate::ui::log::{LogEntry, LogKind};
+
+/// Characters the typewriter reveals per tick when there is little to type.
+pub const TYPE_CHARS_PER_TICK: usize = 6;
+/// A long burst of output, e.g. HELP, is typed out within this many ticks.
+pub const CATCH_UP_TICKS: usize = 10;
+pub const BOOT_TICKS_PER_LINE: u32 = 2;
+/// Ticks the finished boot screen stays up before the terminal appears.
+pub const BOOT_HOLD_TICKS: u32 = 6;
+
+/// Effects are on unless `TERMINAL_COMPANY_EFFECTS` is `off`, `0` or `false`.
+pub fn effects_enabled() -> bool {
+    std::env::var("TERMINAL_COMPANY_EFFECTS")
+        .map(|v| !matches!(v.trim().to_lowercase().as_str(), "off" | "0" | "false"))
+        .unwrap_or(true)
+}
+
+/// How much of a log entry the typewriter lets through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reveal {
+    Full,
+    /// Only the first this-many characters.
+    Partial(usize),
+    Hidden,
+}
+
+/// Reveals new log entries a few characters per tick. Separators and tables
+/// appear whole.
+#[derive(Debug, Clone, Default)]
+pub struct Typewriter {
+    pub enabled: bool,
+    /// Entries before this index are fully shown.
+    shown: usize,
+    /// Characters shown of the entry at `shown`.
+    typed: usize,
+    /// Characters per tick for the current burst; only grows until the
+    /// typewriter catches up.
+    speed: usize,
+}
+
+fn typed_length(entry: &LogEntry) -> usize {
+    if entry.table.is_some() || entry.kind == LogKind::Separator {
+        0
+    } else {
+        entry.text.chars().count()
+    }
+}
+
+impl Typewriter {
+    pub fn new(enabled: bool) -> Self {
+        Typewriter {
+            enabled,
+            ..Typewriter::default()
+        }
+    }
+
+    /// Turns the effect on or off. Either way, what is already in the log
+    /// stays fully shown.
+    pub fn set_enabled(&mut self, enabled: bool, log: &[LogEntry]) {
+        self.enabled = enabled;
+        self.finish(log);
+    }
+
+    pub fn is_typing(&self, log: &[LogEntry]) -> bool {
+        self.enabled && self.shown < log.len()
+    }
+
+    pub fn tick(&mut self, log: &[LogEntry]) {
+        if !self.is_typing(log) {
+            return;
+        }
+        let backlog: usize = log[self.shown..].iter().map(typed_length).sum::<usize>() - self.typed;
+        self.speed = self.speed.max(TYPE_CHARS_PER_TICK).max(backlog.div_ceil(CATCH_UP_TICKS));
+        let mut budget = self.speed;
+
+        while let Some(entry) = log.get(self.shown) {
+            let remaining = typed_length(entry) - self.typed;
+            if remaining > budget {
+                self.typed += budget;
+                break;
+            }
+            budget -= remaining;
+            self.shown += 1;
+            self.typed = 0;
+        }
+        if self.shown == log.len() {
+            self.speed = 0;
+        }
+    }
+
+    /// Shows everything at once, e.g. when a key is pressed.
+    pub fn finish(&mut self, log: &[LogEntry]) {
+        self.shown = log.len();
+        self.typed = 0;
+        self.speed = 0;
+    }
+
+    /// Keeps the position right after `count` entries were dropped from the
+    /// front of the log.
+    pub fn dropped(&mut self, count: usize) {
+        if count > self.shown {
+            self.typed = 0;
+        }
+        self.shown = self.shown.saturating_sub(count);
+    }
+
+    pub fn reveal(&self, index: usize) -> Reveal {
+        if !self.enabled || index < self.shown {
+            Reveal::Full
+        } else if index == self.shown {
+            Reveal::Partial(self.typed)
+        } else {
+            Reveal::Hidden
+        }
+    }
+}
+
+/// The start-up screen: the Company's documents scroll past line by line.
+#[derive(Debug, Clone)]
+pub struct BootScreen {
+    pub lines: Vec<String>,
+    ticks: u32,
+}
+
+impl Default for BootScreen {
+    fn default() -> Self {
+        let mut lines = vec!["Booting Terminal Company OS...".to_string(), String::new()];
+        lines.extend(BOOT_DOCUMENTS.iter().map(|doc| doc.to_string()));
+        lines.push(String::new());
+        lines.push("Operator link established. Press any key.".to_string());
+        BootScreen { lines, ticks: 0 }
+    }
+}
+
+impl BootScreen {
+    pub fn tick(&mut self) {
+        self.ticks += 1;
+    }
+
+    pub fn visible_lines(&self) -> &[String] {
+        let count = (self.ticks / BOOT_TICKS_PER_LINE + 1) as usize;
+        &self.lines[..count.min(self.lines.len())]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.ticks >= self.lines.len() as u32 * BOOT_TICKS_PER_LINE + BOOT_HOLD_TICKS
+    }
+}
@@ -1,5 +1,9 @@
-use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
+use crate::ui::bus;
+use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, MouseEvent};
+use futures_util::StreamExt;
+use std::io;
 use std::time::Duration;
+use tokio::time::{interval, Interval, MissedTickBehavior};
 
 #[derive(Debug, Clone)]
 pub enum Event {
@@ -7,27 +11,43 @@ pub enum Event {
     Mouse(MouseEvent),
     Resize(u16, u16),
     Tick,
+    /// Something was published on the UI event bus and is waiting to be drained.
+    Bus,
 }
 
+/// Merges terminal input, a steady tick and the UI event bus into one async
+/// stream, so nothing blocks the runtime while waiting for the next event.
 pub struct EventHandler {
-    tick_rate: Duration,
+    terminal: EventStream,
+    ticker: Interval,
 }
 
 impl EventHandler {
+    /// Must be called from inside a tokio runtime.
     pub fn new(tick_rate: Duration) -> Self {
-        Self { tick_rate }
+        let mut ticker = interval(tick_rate);
+        // A slow frame shouldn't be followed by a burst of catch-up ticks.
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self {
+            terminal: EventStream::new(),
+            ticker,
+        }
     }
 
-    pub fn next(&self) -> std::io::Result<Event> {
-        if event::poll(self.tick_rate)? {
-            match event::read()? {
-                CrosstermEvent::Key(key) => Ok(Event::Key(key)),
-                CrosstermEvent::Mouse(mouse) => Ok(Event::Mouse(mouse)),
-                CrosstermEvent::Resize(w, h) => Ok(Event::Resize(w, h)),
-                _ => Ok(Event::Tick),
+    pub async fn next(&mut self) -> io::Result<Event> {
+        loop {
+            tokio::select! {
+                _ = self.ticker.tick() => return Ok(Event::Tick),
+                _ = bus::wait() => return Ok(Event::Bus),
+                event = self.terminal.next() => match event {
+                    Some(Ok(CrosstermEvent::Key(key))) => return Ok(Event::Key(key)),
+                    Some(Ok(CrosstermEvent::Mouse(mouse))) => return Ok(Event::Mouse(mouse)),
+                    Some(Ok(CrosstermEvent::Resize(w, h))) => return Ok(Event::Resize(w, h)),
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e),
+                    None => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "terminal event stream closed")),
+                },
             }
-        } else {
-            Ok(Event::Tick)
         }
     }
 }
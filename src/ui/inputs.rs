@@ -1,8 +1,22 @@
 use crate::ui::app::{App, ConfirmationType, InputMode};
 use crate::commands::commands_fn;
+use crate::models::encounter::EncounterAction;
+use crate::models::items::player_weight_penalty;
+use crate::models::ledger::TransactionKind;
+use crate::models::types::Item;
+use crate::models::time_of_day::{scan_at_time, COLLECT_MINUTES, LANDING_TIME, SCAN_MINUTES, WAIT_MINUTES};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
 pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    use crate::ui::components::{log_line_at, screen_layout, tab_at};
+    use crossterm::event::MouseButton;
+    
+    if app.boot.is_some() {
+        return;
+    }
+    let layout = screen_layout(app.screen(), &app.panels);
+    let line_at = |app: &App| log_line_at(app, layout.log, mouse.column, mouse.row);
+    
     match mouse.kind {
         MouseEventKind::ScrollUp => {
             app.scroll_up(3);
@@ -10,15 +24,112 @@ pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
         MouseEventKind::ScrollDown => {
             app.scroll_down(3);
         }
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(tabs) = layout.tabs
+                && mouse.row == tabs.y
+                && let Some(panel) = tab_at(tabs, mouse.column)
+            {
+                app.panels.toggle(panel);
+                return;
+            }
+            app.selection = line_at(app).map(|line| (line, line));
+        }
+        MouseEventKind::Drag(MouseButton::Left) => {
+            if let Some((anchor, _)) = app.selection
+                && let Some(line) = line_at(app)
+            {
+                app.selection = Some((anchor, line));
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) => {
+            if let Some(selection) = app.selection.take() {
+                handle_log_click(app, layout.log.width, selection);
+            }
+        }
         _ => {}
     }
 }
 
+/// A click on one log line runs its action, if it has one; a drag over
+/// several lines copies them to the clipboard.
+fn handle_log_click(app: &mut App, log_width: u16, (anchor, cursor): (usize, usize)) {
+    use crate::ui::components::log_lines;
+    
+    let lines = log_lines(app, log_width.saturating_sub(2) as usize);
+    if anchor == cursor {
+        let action = lines.get(anchor).and_then(|line| app.message_log[line.entry].action.clone());
+        if let Some(command) = action
+            && app.input_mode == InputMode::Normal
+            && !app.tasks.is_busy()
+        {
+            app.set_input(&command);
+        }
+        return;
+    }
+    
+    let selected: Vec<&str> = lines
+        .get(anchor.min(cursor)..=anchor.max(cursor).min(lines.len().saturating_sub(1)))
+        .unwrap_or_default()
+        .iter()
+        .map(|line| line.text.as_str())
+        .collect();
+    match crate::ui::clipboard::copy(&selected.join("\n")) {
+        Ok(()) => app.add_success(&format!("📋 Copied {} line(s) to the clipboard.", selected.len())),
+        Err(e) => app.add_error(&format!("⚠️ Failed to copy: {}", e)),
+    }
+}
+
 pub async fn handle_key_event(app: &mut App, key: KeyEvent) {
+    use crate::ui::app::Panel;
+    
+    // Any key skips the boot screen and finishes the typewriter.
+    if app.skip_effects() {
+        return;
+    }
+    
+    let panel = match key.code {
+        KeyCode::F(2) => Some(Panel::Status),
+        KeyCode::F(3) => Some(Panel::Scan),
+        KeyCode::F(4) => Some(Panel::Inventory),
+        _ => None,
+    };
+    if let Some(panel) = panel {
+        app.panels.toggle(panel);
+        return;
+    }
+    
+    if app.tasks.is_busy() {
+        handle_busy_mode(app, key);
+        return;
+    }
+    
     match app.input_mode {
         InputMode::Normal => handle_normal_mode(app, key).await,
         InputMode::Confirmation => handle_confirmation_mode(app, key).await,
         InputMode::Editing => handle_editing_mode(app, key).await,
+        InputMode::Search => handle_search_mode(app, key).await,
+    }
+}
+
+/// Keys that still work while a command runs in the background.
+fn handle_busy_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.should_quit = true;
+        }
+        KeyCode::Esc => {
+            if let Some(label) = app.tasks.cancel() {
+                app.is_processing = false;
+                app.add_warning(&format!("⚠️ {} cancelled.", label));
+            }
+        }
+        KeyCode::PageUp => {
+            app.scroll_up(10);
+        }
+        KeyCode::PageDown => {
+            app.scroll_down(10);
+        }
+        _ => {}
     }
 }
 
@@ -33,16 +144,18 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) {
                 "Are you sure you want to quit?"
             );
         }
-        KeyCode::Enter => {
-            if !app.input.is_empty() {
-                let command = app.input.trim().to_uppercase();
-                app.add_message(&format!("> {}", command));
-                app.clear_input();
-                
-                app.scroll_to_bottom();
-                
-                execute_command(app, &command).await;
-            }
+        KeyCode::Char('/') if app.input.is_empty() => {
+            start_search(app);
+        }
+        KeyCode::Enter if !app.input.is_empty() => {
+            let raw = app.input.trim().to_string();
+            let command = raw.to_uppercase();
+            app.add_command(&command);
+            app.clear_input();
+            
+            app.scroll_to_bottom();
+            
+            execute_command(app, &command, &raw).await;
         }
         KeyCode::Char(c) => {
             app.enter_char(c);
@@ -72,11 +185,107 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn start_search(app: &mut App) {
+    use crate::ui::search::Search;
+    
+    app.search = Some(Search {
+        editing: true,
+        ..Search::default()
+    });
+    app.input_mode = InputMode::Search;
+    app.clear_input();
+}
+
+fn close_search(app: &mut App) {
+    app.search = None;
+    app.input_mode = InputMode::Normal;
+    app.clear_input();
+}
+
+/// Typing a `/` query, then stepping through its matches with n and N.
+/// Any other character closes the search and starts a command.
+async fn handle_search_mode(app: &mut App, key: KeyEvent) {
+    use crate::ui::search::SearchDirection;
+    
+    let editing = app.search.as_ref().is_some_and(|s| s.editing);
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.should_quit = true;
+        }
+        KeyCode::Esc => close_search(app),
+        KeyCode::PageUp => app.scroll_up(10),
+        KeyCode::PageDown => app.scroll_down(10),
+        KeyCode::Enter if editing => run_search(app),
+        KeyCode::Backspace if editing && app.input.is_empty() => close_search(app),
+        KeyCode::Backspace if editing => app.delete_char(),
+        KeyCode::Left if editing => app.move_cursor_left(),
+        KeyCode::Right if editing => app.move_cursor_right(),
+        KeyCode::Char(c) if editing => app.enter_char(c),
+        KeyCode::Enter => close_search(app),
+        KeyCode::Char('n') => step_search(app, SearchDirection::Older),
+        KeyCode::Char('N') => step_search(app, SearchDirection::Newer),
+        KeyCode::Char('/') => start_search(app),
+        KeyCode::Char(_) => {
+            close_search(app);
+            handle_normal_mode(app, key).await;
+        }
+        _ => {}
+    }
+}
+
+fn run_search(app: &mut App) {
+    use crate::ui::search::SearchDirection;
+    
+    let query = app.input.trim().to_string();
+    if query.is_empty() {
+        close_search(app);
+        return;
+    }
+    if let Some(search) = app.search.as_mut() {
+        search.query = query;
+        search.editing = false;
+        search.current = None;
+    }
+    app.clear_input();
+    step_search(app, SearchDirection::Older);
+}
+
+/// Moves to the next match and scrolls it into the middle of the log pane.
+fn step_search(app: &mut App, direction: crate::ui::search::SearchDirection) {
+    use crate::ui::components::{log_lines, screen_layout};
+    use crate::ui::search::{centre_on, find_matches, next_match};
+    
+    let Some(search) = app.search.clone() else {
+        return;
+    };
+    let layout = screen_layout(app.screen(), &app.panels);
+    let lines = log_lines(app, layout.log.width.saturating_sub(2) as usize);
+    let matches = find_matches(lines.iter().map(|l| l.text.as_str()), &search.query);
+    
+    let Some(index) = next_match(&matches, search.current, direction) else {
+        close_search(app);
+        app.add_warning(&format!("⚠️ No matches for '{}'.", search.query));
+        return;
+    };
+    let height = layout.log.height.saturating_sub(2) as usize;
+    app.scroll_offset = centre_on(index, lines.len(), height);
+    app.auto_scroll = false;
+    if let Some(search) = app.search.as_mut() {
+        search.current = Some(index);
+        search.total = matches.len();
+        search.position = matches.len() - matches.iter().position(|m| *m == index).unwrap_or(0);
+    }
+}
+
 async fn handle_confirmation_mode(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Enter => {
-            let response = app.input.trim().to_uppercase();
-            app.add_message(&format!("> {}", response));
+            let raw = app.input.trim().to_string();
+            let response = raw.to_uppercase();
+            match app.confirmation_type {
+                Some(ConfirmationType::OperatorName | ConfirmationType::OperatorRole(_)) => app.add_command(&raw),
+                _ => app.add_command(&response),
+            }
             
             if let Some(conf_type) = app.confirmation_type.clone() {
                 match conf_type {
@@ -90,27 +299,31 @@ async fn handle_confirmation_mode(app: &mut App, key: KeyEvent) {
                     }
                     ConfirmationType::NewGame => {
                         if response == "YES" || response == "Y" {
-                            app.is_processing = true;
-                            app.add_message("Starting new game...");
-                            
-                            // Delete existing game state
-                            commands_fn::delete_game_state(&app.db_client).await;
-                            match crate::commands::registration::handle_registration(app.db_client.clone()).await {
-                                Ok(new_state) => {
-                                    app.game_state = new_state;
-                                    app.add_message("✨ New game started!");
-                                    app.add_message(&format!("Welcome, {}!", app.game_state.players[0].name));
-                                }
-                                Err(e) => {
-                                    app.add_message(&format!("⚠️ Error starting new game: {}", e));
-                                }
-                            }
-                            
-                            app.is_processing = false;
+                            app.add_separator();
+                            app.request_confirmation(ConfirmationType::OperatorName, "Please enter your Operator data.");
+                            app.clear_input();
+                            return;
                         } else {
                             app.add_message("Cancelled starting a new game.");
                         }
                     }
+                    ConfirmationType::OperatorName => {
+                        if raw.is_empty() {
+                            app.add_message("Please enter a name.");
+                        } else {
+                            app.confirmation_type = Some(ConfirmationType::OperatorRole(raw));
+                        }
+                        app.clear_input();
+                        return;
+                    }
+                    ConfirmationType::OperatorRole(name) => {
+                        if raw.is_empty() {
+                            app.add_message("Please enter a role.");
+                            app.clear_input();
+                            return;
+                        }
+                        start_new_game(app, &name, &raw);
+                    }
                     ConfirmationType::AcceptTerms => {
                         if response == "ACCEPT" {
                             app.add_message("Thank you. Access granted.");
@@ -123,37 +336,75 @@ async fn handle_confirmation_mode(app: &mut App, key: KeyEvent) {
                             return;
                         }
                     }
+                    ConfirmationType::Checkout => {
+                        if response == "YES" || response == "Y" {
+                            use crate::models::store::checkout;
+                            
+                            let cart = app.game_state.cart.clone();
+                            let stored = app.game_state.ship.storage.len();
+                            let game_state = &mut app.game_state;
+                            match checkout(&mut game_state.players[0], &mut game_state.ship.storage, &cart) {
+                                Ok(spent) => {
+                                    let note = format!("Cart checkout ({} lines)", cart.len());
+                                    app.game_state.record_transaction(TransactionKind::Purchase, -(spent as i64), &note);
+                                    app.game_state.cart.clear();
+                                    app.add_success(&format!("✨ Order complete: {} credits spent.", spent));
+                                    report_delivery(app, stored);
+                                    app.add_message(&format!("Your remaining credits: {}", app.game_state.players[0].credits));
+                                }
+                                Err(e) => {
+                                    app.add_warning(&format!("⚠️ Nothing was bought. {}", e));
+                                }
+                            }
+                        } else {
+                            app.add_message("Checkout cancelled. Your cart is unchanged.");
+                        }
+                    }
                     ConfirmationType::Collect => {
                         if response == "YES" || response == "Y" {
                             app.is_processing = true;
                             
                             let location_key = app.game_state.ship.location.clone();
-                            if let Some(scan_data) = app.game_state.scan_data.get(&location_key) {
-                                match crate::data::mongodb::load_collect_config(&app.db_client).await {
-                                    Ok(config) => {
-                                        use crate::models::collect_credits::CollectCreditsEvent;
-                                        let event = CollectCreditsEvent {
-                                            scan_data,
-                                            player_bonus: 0,
-                                            config: &config,
-                                        };
-                                        
-                                        match event.attempt() {
-                                            Some(credits) => {
-                                                app.add_message(&format!("✅ You found {} credits!", credits));
-                                                app.game_state.players[0].credits += credits;
-                                            }
-                                            None => {
-                                                app.add_message("❌ No credits found this time.");
-                                            }
-                                        }
+                            let now = app.game_state.landed_time.unwrap_or(LANDING_TIME);
+                            if let Some(scan_data) = app.game_state.scan_data.get(&location_key).map(|s| scan_at_time(s, now)) {
+                                use crate::models::collect_credits::CollectCreditsEvent;
+                                let penalty = player_weight_penalty(&app.game_state.players[0], app.game_state.carried_scrap());
+                                let event = CollectCreditsEvent {
+                                    scan_data: &scan_data,
+                                    player_bonus: -(penalty as i32),
+                                    config: &app.game_data.collect_config,
+                                };
+                                
+                                match event.attempt() {
+                                    Some(credits) => {
+                                        app.add_success(&format!("✅ You found {} credits!", credits));
+                                        app.game_state.players[0].credits += credits;
+                                        app.game_state.record_transaction(TransactionKind::Collect, credits as i64, "Scrap collected");
+                                    }
+                                    None => {
+                                        app.add_warning("❌ No credits found this time.");
                                     }
-                                    Err(e) => {
-                                        app.add_message(&format!("⚠️ Error loading collect config: {}", e));
+                                }
+                            }
+                            
+                            // Going in reveals what the scanner could only estimate
+                            if let Some(scan_data) = app.game_state.scan_data.get_mut(&location_key) {
+                                scan_data.depleted = true;
+                                scan_data.revealed = true;
+                                let scan_data = scan_data.clone();
+                                app.add_message(&format!(
+                                    "📋 Actual scrap value on site: {} credits, threat level {}%.",
+                                    scan_data.scrap_value, scan_data.threat_level
+                                ));
+                                for monster in &scan_data.monsters {
+                                    if app.game_state.discover_creature(&monster.name) {
+                                        app.add_message(&format!("📖 New bestiary entry: {}", monster.name));
                                     }
                                 }
                             }
                             
+                            spend_time(app, COLLECT_MINUTES);
+                            
                             app.is_processing = false;
                         } else {
                             app.add_message("You decided not to collect this time.");
@@ -186,7 +437,7 @@ async fn handle_editing_mode(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Enter => {
             let value = app.input.clone();
-            app.add_message(&format!("> {}", value));
+            app.add_command(&value);
             
             app.input_mode = InputMode::Normal;
             app.clear_input();
@@ -206,11 +457,25 @@ async fn handle_editing_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
-async fn execute_command(app: &mut App, command: &str) {
+/// Runs `command`, the uppercased form of `raw`, what the player typed.
+async fn execute_command(app: &mut App, command: &str, raw: &str) {
     app.is_processing = true;
+    // Empty, blocked and unknown commands don't take a turn.
+    let mut executed = true;
     
     let result = match command {
-        "" => return,
+        "" => {
+            executed = false;
+            Ok(())
+        }
+        cmd if app.game_state.encounter.is_some() && !allowed_in_encounter(cmd) => {
+            executed = false;
+            if let Some(encounter) = &app.game_state.encounter {
+                let name = encounter.monster.name.clone();
+                app.add_warning(&format!("⚠️ The {} is on you! USE [item], RUN or HIDE.", name));
+            }
+            Ok(())
+        }
         "MOONS" => {
             handle_moons(app);
             Ok::<(), Box<dyn std::error::Error>>(())
@@ -224,17 +489,33 @@ async fn execute_command(app: &mut App, command: &str) {
             Ok(())
         }
         "SCAN" => {
-            handle_scan(app).await;
+            handle_scan(app);
+            Ok(())
+        }
+        "SCAN DETAIL" => {
+            handle_scan_detail(app);
+            Ok(())
+        }
+        "RESCAN" => {
+            handle_rescan(app);
+            Ok(())
+        }
+        "WAIT" => {
+            handle_wait(app);
             Ok(())
         }
         "COLLECT" => {
-            handle_collect(app).await;
+            handle_collect(app);
             Ok(())
         }
         "BESTIARY" => {
             handle_monsters(app);
             Ok(())
         }
+        "MODS" => {
+            handle_mods(app);
+            Ok(())
+        }
         "LOCATION" | "LOC" => {
             handle_location(app);
             Ok(())
@@ -244,11 +525,31 @@ async fn execute_command(app: &mut App, command: &str) {
             Ok(())
         }
         "SAVE" => {
-            handle_save(app).await;
+            handle_save(app);
             Ok(())
         }
         "LOAD" => {
-            handle_load(app).await;
+            handle_load(app);
+            Ok(())
+        }
+        "ENTER FACILITY" => {
+            handle_enter_facility(app);
+            Ok(())
+        }
+        "LOOK" => {
+            handle_look(app);
+            Ok(())
+        }
+        "RUN" => {
+            handle_encounter(app, EncounterAction::Run);
+            Ok(())
+        }
+        "HIDE" => {
+            handle_encounter(app, EncounterAction::Hide);
+            Ok(())
+        }
+        "EXIT" if app.game_state.facility.is_some() => {
+            handle_exit_facility(app);
             Ok(())
         }
         "QUIT" | "EXIT" => {
@@ -270,209 +571,993 @@ async fn execute_command(app: &mut App, command: &str) {
             handle_go_to(app, moon);
             Ok(())
         }
+        cmd if cmd.starts_with("MOVE ") => {
+            handle_move(app, cmd[5..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("USE ") => {
+            handle_use(app, cmd[4..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("EQUIP ") => {
+            handle_equip(app, cmd[6..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("DROP ") => {
+            handle_drop(app, cmd[5..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("TAKE ") => {
+            handle_take(app, cmd[5..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("CHARGE ") => {
+            handle_charge(app, cmd[7..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("GRAB ") => {
+            handle_grab(app, cmd[5..].trim());
+            Ok(())
+        }
+        cmd if cmd.starts_with("BESTIARY ") => {
+            let name = cmd[9..].trim();
+            handle_monster_page(app, name);
+            Ok(())
+        }
+        "CART" => {
+            handle_cart(app);
+            Ok(())
+        }
+        "CART CLEAR" => {
+            app.game_state.cart.clear();
+            app.add_message("🛒 Your cart is now empty.");
+            Ok(())
+        }
+        cmd if cmd.starts_with("CART ADD ") => {
+            handle_cart_add(app, cmd[9..].trim());
+            Ok(())
+        }
+        "LEDGER" => {
+            handle_ledger(app, "");
+            Ok(())
+        }
+        cmd if cmd.starts_with("LEDGER ") => {
+            handle_ledger(app, cmd[7..].trim());
+            Ok(())
+        }
+        "EFFECTS" => {
+            handle_effects(app, "");
+            Ok(())
+        }
+        cmd if cmd.starts_with("EFFECTS ") => {
+            handle_effects(app, cmd[8..].trim());
+            Ok(())
+        }
+        "THEME" => {
+            handle_theme(app, "");
+            Ok(())
+        }
+        cmd if cmd.starts_with("THEME ") => {
+            handle_theme(app, cmd[6..].trim());
+            Ok(())
+        }
+        "LOG" => {
+            handle_log(app, "", raw);
+            Ok(())
+        }
+        cmd if cmd.starts_with("LOG ") => {
+            handle_log(app, cmd[4..].trim(), raw);
+            Ok(())
+        }
+        "CHECKOUT" => {
+            handle_checkout(app);
+            Ok(())
+        }
+        "SELL" => {
+            app.add_warning("⚠️ Usage: SELL [item] or SELL SCRAP");
+            Ok(())
+        }
+        cmd if cmd.starts_with("SELL ") => {
+            handle_sell(app, cmd[5..].trim());
+            Ok(())
+        }
         cmd if cmd.starts_with("BUY ") => {
             handle_buy(app, cmd);
             Ok(())
         }
         _ => {
-            app.add_message(&format!("⚠️ Unknown command: '{}'. Type HELP for available commands.", command));
+            executed = false;
+            app.add_warning(&format!("⚠️ Unknown command: '{}'. Type HELP for available commands.", command));
             Ok(())
         }
     };
     
     if let Err(e) = result {
-        app.add_message(&format!("⚠️ Command failed: {:?}", e));
+        app.add_error(&format!("⚠️ Command failed: {:?}", e));
+    }
+    if executed {
+        app.game_state.turn_number += 1;
     }
     
-    app.is_processing = false;
+    app.is_processing = app.tasks.is_busy();
 }
 
 // UI-friendly versions of commands that add messages to the app instead of printing
 
 fn handle_moons(app: &mut App) {
-    use crate::models::lists::MOONS;
-    app.add_message(&format!("Visitable: {}", MOONS.join(", ")));
+    use crate::utils::shortcut::format_name;
+    
+    app.add_message("Visitable (click one to travel):");
+    let moons = app.game_data.moons.clone();
+    for moon in &moons {
+        app.add_action(&format!("- {}", format_name(moon)), &format!("GO TO {}", moon));
+    }
 }
 
 fn handle_store(app: &mut App) {
-    use crate::models::lists::STORE_ITEMS;
     use crate::utils::shortcut::format_name;
     
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    app.add_message("Available Items:");
-    for item in STORE_ITEMS.iter() {
-        app.add_message(&format!("- {}:", format_name(&item.name)));
-        app.add_message(&format!("  💰 Price: {} credits", item.price));
+    let prices = app.prices();
+    let quota = app.game_state.quota.clone();
+    
+    app.add_separator();
+    app.add_message(&format!("Available Items (Day {}, click a name to buy):", app.game_state.day));
+    app.add_message(&format!("Quota: {}/{} credits", quota.fulfilled, quota.target));
+    if prices.markup > 0 {
+        app.add_message(&format!("The Company has raised prices by {}% for crews on track.", prices.markup));
+    }
+    app.add_separator();
+    let store_items = app.game_data.store_items.clone();
+    for item in store_items.iter() {
+        app.add_action(&format!("- {}:", format_name(&item.name)), &format!("BUY {}", item.name));
+        match prices.discount(item) {
+            Some(discount) => app.add_message(&format!(
+                "  💰 Price: {} → {} credits (🏷️ {}% OFF today)",
+                prices.list_price(item),
+                prices.price(item),
+                discount
+            )),
+            None => app.add_message(&format!("  💰 Price: {} credits", prices.price(item))),
+        }
         app.add_message(&format!("  ⚖️  Weight: {}", item.weight));
         app.add_message(&format!("  📝 Description: {}", item.description));
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        app.add_separator();
+    }
+}
+
+/// Equipped state, charge and durability for the INVENTORY table.
+fn item_status(item: &Item) -> String {
+    let mut status = vec![];
+    if item.equipped {
+        status.push("[EQUIPPED]".to_string());
+    }
+    if let Some(charge) = item.charge {
+        status.push(format!("🔋 {}%", charge));
     }
+    if let Some(uses) = item.uses_remaining {
+        status.push(format!("🔧 {} uses", uses));
+    }
+    status.join(" ")
 }
 
 fn handle_inv(app: &mut App) {
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    app.add_message("Your Inventory Status:");
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    use crate::models::items::{capacity, load_weight, slots_used, slotted, weight_penalty, HAND_SLOTS};
+    use crate::models::types::EquipSlot;
+    
+    app.add_separator();
+    app.add_heading("Your Inventory Status:");
+    app.add_separator();
     
-    let is_empty = app.game_state.players[0].inventory.is_empty();
+    let player = app.game_state.players[0].clone();
     
-    if is_empty {
+    if player.inventory.is_empty() {
         app.add_message("Your inventory is currently empty. Buy some items from the 'store'!");
     } else {
-        let inventory = app.game_state.players[0].inventory.clone();
-        for item in inventory.iter() {
-            app.add_message(&format!("- {}: {} credits", item.name, item.price));
-            app.add_message(&format!("  ⚖️  Weight: {}", item.weight));
-            app.add_message(&format!("  📝 Description: {}", item.description));
+        let row = |slot: String, item: &Item| vec![slot, item.name.clone(), format!("{} lb", item.weight), item_status(item)];
+        let mut rows: Vec<Vec<String>> = slotted(&player)
+            .enumerate()
+            .map(|(i, item)| {
+                let slot = if i < HAND_SLOTS { (i + 1).to_string() } else { format!("Bag{}", i + 1 - HAND_SLOTS) };
+                row(slot, item)
+            })
+            .collect();
+        if let Some(bag) = player.inventory.iter().find(|i| i.slot == Some(EquipSlot::Belt)) {
+            rows.push(row("Belt".to_string(), bag));
+        }
+        app.add_table(&["SLOT", "ITEM", "WEIGHT", "STATUS"], rows);
+        
+        let weight = load_weight(&player, app.game_state.carried_scrap());
+        app.add_message(&format!(
+            "Slots: {}/{}   Total weight: {} lb   Penalty: -{}%",
+            slots_used(&player),
+            capacity(&player),
+            weight,
+            weight_penalty(weight)
+        ));
+        for item in &player.inventory {
+            app.add_message(&format!("  📝 {}: {}", item.name, item.description));
         }
     }
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    
+    let storage: Vec<String> = app.game_state.ship.storage.iter().map(|i| i.name.clone()).collect();
+    if !storage.is_empty() {
+        app.add_message(&format!("In the ship's storage: {}", storage.join(", ")));
+    }
+    
+    let ship_scrap = app.game_state.ship.scrap.clone();
+    if !ship_scrap.is_empty() {
+        let total: u32 = ship_scrap.iter().map(|s| s.value).sum();
+        app.add_message(&format!("Scrap aboard the ship ({} credits):", total));
+        for scrap in &ship_scrap {
+            app.add_message(&format!("- {}: {} credits", scrap.name, scrap.value));
+        }
+    }
+    app.add_separator();
 }
 
-async fn handle_scan(app: &mut App) {
+fn handle_scan(app: &mut App) {
     use crate::utils::shortcut::format_name;
-    use crate::data::mongodb::load_bestiary;
-    use crate::models::scan_logic::generate_scan_data;
-    use rand::Rng;
+    use crate::models::scan_logic::is_scan_expired;
     
     if commands_fn::is_at_company(&app.game_state) {
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        app.add_separator();
         app.add_message("You can't scan anything while at the Company building.");
         app.add_message("Use 'GO TO [moon name]' to travel to a moon.");
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        app.add_separator();
         return;
     }
     
     let location_key = app.game_state.ship.location.clone();
     
-    if let Some(scan_data) = app.game_state.scan_data.get(&location_key).cloned() {
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        app.add_message(&format!("Scan data for {} is already available:", format_name(&location_key)));
-        app.add_message(&format!("☁️  Weather: {}", scan_data.weather));
-        app.add_message(&format!("⚠️  Threat Level: {}%", scan_data.threat_level));
-        app.add_message(&format!("💰 Estimated Scrap Value: {} credits", scan_data.scrap_value));
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    } else {
-        let mut rng = rand::rng();
-        let weather_conditions = ["Clear", "Rainy", "Foggy", "Stormy", "Eclipsed"];
-        let random_weather = weather_conditions[rng.random_range(0..weather_conditions.len())].to_string();
-        let random_scrap_value = rng.random_range(100..1001);
-        
-        let bestiary = match load_bestiary(&app.db_client).await {
-            Ok(b) => b,
-            Err(e) => {
-                app.add_message(&format!("⚠️ Error loading bestiary: {}", e));
-                return;
-            }
-        };
-        
-        let scan_data = generate_scan_data(
-            &app.game_state.ship.location,
-            &random_weather,
-            random_scrap_value,
-            &bestiary,
-        );
-        
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        app.add_message(&format!("Scanning environment on {}...", format_name(&location_key)));
-        app.add_message("Monsters detected:");
-        if scan_data.monsters.is_empty() {
-            app.add_message("- None detected");
-        } else {
-            for monster in &scan_data.monsters {
-                app.add_message(&format!("- {} (Danger: {})", monster.name, monster.danger_level.unwrap_or(0)));
+    match app.game_state.scan_data.get(&location_key).cloned() {
+        Some(scan_data) if !is_scan_expired(&scan_data, app.game_state.day) => {
+            app.add_separator();
+            app.add_message(&format!("Scan data for {} is already available (day {}):", format_name(&location_key), scan_data.day));
+            show_scan_report(app, &scan_data);
+            if scan_data.depleted {
+                app.add_message("🪫 This moon has already been picked clean today.");
             }
+            app.add_message("Use 'RESCAN' to force a fresh scan.");
+            app.add_separator();
+        }
+        Some(_) => {
+            app.add_message(&format!("Scan data for {} has expired. Scanning again...", format_name(&location_key)));
+            scan_moon(app);
+        }
+        None => {
+            scan_moon(app);
         }
-        app.add_message("Scan data generated:");
-        app.add_message(&format!("☁️  Weather: {}", scan_data.weather));
-        app.add_message(&format!("⚠️  Threat Level: {}%", scan_data.threat_level));
-        app.add_message(&format!("💰 Estimated Scrap Value: {} credits", scan_data.scrap_value));
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        
-        app.game_state.scan_data.insert(location_key, scan_data);
     }
 }
 
-async fn handle_collect(app: &mut App) {
-    use crate::data::mongodb::load_collect_config;
-    use crate::models::collect_credits::CollectCreditsEvent;
+fn handle_scan_detail(app: &mut App) {
+    use crate::models::scan_logic::is_scan_expired;
+    use crate::models::scan_report::{build_report, scan_confidence, Sighting};
+    use crate::models::threat::assess;
     use crate::utils::shortcut::format_name;
     
     let location_key = app.game_state.ship.location.clone();
+    let scan_data = match app.game_state.scan_data.get(&location_key) {
+        Some(scan_data) if !is_scan_expired(scan_data, app.game_state.day) => scan_data,
+        _ => {
+            app.add_warning(&format!("⚠️ No scan data available for {}. Use 'SCAN' first.", format_name(&location_key)));
+            return;
+        }
+    };
     
-    if let Some(scan_data) = app.game_state.scan_data.get(&location_key) {
-        match load_collect_config(&app.db_client).await {
-            Ok(config) => {
-                let event = CollectCreditsEvent {
-                    scan_data,
-                    player_bonus: 0,
-                    config: &config,
-                };
-                
-                let chance = event.calculate_chance();
-                app.add_message(&format!("Chance to collect credits: {}%", chance));
-                app.add_message("Do you want to attempt collecting? (YES/NO)");
-                
-                app.input_mode = InputMode::Confirmation;
-                app.confirmation_type = Some(ConfirmationType::Collect);
-            }
-            Err(e) => {
-                app.add_message(&format!("⚠️ Error loading collect config: {}", e));
-            }
+    let active_scan = scan_at_time(scan_data, app.game_state.landed_time.unwrap_or(LANDING_TIME));
+    let monsters: Vec<&crate::models::types::Monster> = active_scan.monsters.iter().collect();
+    let breakdown = assess(&monsters, &active_scan.weather);
+    let confidence = scan_confidence(&app.game_state.players[0], &app.game_state.ship);
+    let report = build_report(&active_scan, confidence, &app.game_state.discovered_creatures);
+    
+    let mut lines = Vec::new();
+    for (sighting, contribution) in report.sightings.iter().zip(&breakdown.contributions) {
+        match sighting {
+            Sighting::Identified { name, .. } => lines.push(format!(
+                "- {}: base {:.1} × behavior {:.1} × placement {:.1} = {:.1}",
+                name, contribution.base, contribution.behavior_factor, contribution.placement_factor, contribution.score
+            )),
+            Sighting::UnknownSignature => lines.push("- ??? Unknown signature: no reading".to_string()),
         }
+    }
+    
+    app.add_separator();
+    app.add_heading(&format!("Threat breakdown for {}:", format_name(&location_key)));
+    app.add_separator();
+    if lines.is_empty() {
+        app.add_message("- No active signatures");
+    }
+    for line in &lines {
+        app.add_message(line);
+    }
+    app.add_message(&format!("Crowd stacking ({} signatures): ×{:.1}", breakdown.contributions.len(), breakdown.stacking_factor));
+    app.add_message(&format!("Weather ({}): ×{:.1}", active_scan.weather, breakdown.weather_factor));
+    let (threat_low, threat_high) = report.threat_range;
+    if threat_low == threat_high {
+        app.add_warning(&format!("⚠️  Threat Level: {}/100", threat_low));
     } else {
-        app.add_message(&format!("⚠️ No scan data available for {}. Use 'SCAN' first.", format_name(&location_key)));
+        app.add_warning(&format!("⚠️  Threat Level: {}-{}/100 ({}% confidence)", threat_low, threat_high, report.confidence));
     }
+    app.add_separator();
 }
 
-fn handle_monsters(app: &mut App) {
-    use crate::models::lists::BESTIARY;
-    use crate::utils::shortcut::format_name;
+fn handle_rescan(app: &mut App) {
+    use crate::models::scan_logic::RESCAN_COST;
     
-    app.add_message("Scannable creatures:");
-    for monster in BESTIARY.iter() {
-        app.add_message(&format!(
-            "- {}: {}",
-            format_name(&monster.name),
-            monster.notes.as_deref().unwrap_or("No description available.")
-        ));
+    if commands_fn::is_at_company(&app.game_state) {
+        app.add_separator();
+        app.add_message("You can't scan anything while at the Company building.");
+        app.add_message("Use 'GO TO [moon name]' to travel to a moon.");
+        app.add_separator();
+        return;
+    }
+    
+    let player_credits = app.game_state.players[0].credits;
+    if player_credits < RESCAN_COST {
+        app.add_warning(&format!("⚠️ A rescan costs {} credits, but you have only {}.", RESCAN_COST, player_credits));
+        return;
     }
+    
+    app.game_state.players[0].credits -= RESCAN_COST;
+    app.game_state.record_transaction(TransactionKind::Rescan, -(RESCAN_COST as i64), "Forced rescan");
+    scan_moon(app);
+    app.add_message(&format!("Rescan charged {} credits. Remaining credits: {}", RESCAN_COST, app.game_state.players[0].credits));
 }
 
-fn handle_location(app: &mut App) {
+/// Generates a fresh scan for the current location and stores it for today.
+fn scan_moon(app: &mut App) {
     use crate::utils::shortcut::format_name;
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    app.add_message(&format!("Your current location is: {}", format_name(&app.game_state.ship.location)));
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    use crate::models::scan_logic::generate_scan_data;
+    use rand::Rng;
+    
+    let location_key = app.game_state.ship.location.clone();
+    
+    let mut rng = rand::rng();
+    let weather_conditions = ["Clear", "Rainy", "Foggy", "Stormy", "Eclipsed"];
+    let random_weather = weather_conditions[rng.random_range(0..weather_conditions.len())].to_string();
+    let random_scrap_value = rng.random_range(100..1001);
+    
+    let bestiary = app.game_data.bestiary();
+    
+    let mut scan_data = generate_scan_data(
+        &location_key,
+        &random_weather,
+        random_scrap_value,
+        &bestiary,
+    );
+    scan_data.day = app.game_state.day;
+    app.game_state.store_scan(&location_key, scan_data);
+    let scan_data = app.game_state.scan_data[&location_key].clone();
+    
+    app.add_separator();
+    app.add_message(&format!("Scanning environment on {}...", format_name(&location_key)));
+    show_scan_report(app, &scan_data);
+    if scan_data.depleted {
+        app.add_message("🪫 This moon has already been picked clean today.");
+    }
+    app.add_message(&format!("📅 Valid until the end of day {}", scan_data.day));
+    app.add_separator();
+    
+    spend_time(app, SCAN_MINUTES);
 }
 
-fn handle_help(app: &mut App) {
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    app.add_message("Commands available:");
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    app.add_message("MOONS            - Lists visitable planets");
-    app.add_message("GO TO [moon]     - Travel to a planet");
-    app.add_message("LOCATION         - Show your current location");
+/// Prints the scanner's estimate for a scan and records identified creatures.
+fn show_scan_report(app: &mut App, scan_data: &crate::models::types::ScanData) {
+    use crate::models::scan_report::{build_report, scan_confidence, Sighting};
+    use crate::models::time_of_day::format_clock;
+    
+    let now = app.game_state.landed_time.unwrap_or(LANDING_TIME);
+    let active_scan = scan_at_time(scan_data, now);
+    let confidence = scan_confidence(&app.game_state.players[0], &app.game_state.ship);
+    let report = build_report(&active_scan, confidence, &app.game_state.discovered_creatures);
+    
+    app.add_message(&format!("🕒 Local time: {}", format_clock(now)));
+    app.add_message(&format!("📡 Scan confidence: {}%", report.confidence));
+    app.add_message("Monsters detected:");
+    if report.sightings.is_empty() {
+        app.add_message("- None detected");
+    } else {
+        for sighting in &report.sightings {
+            match sighting {
+                Sighting::Identified { name, danger_level } => {
+                    app.add_message(&format!("- {} (Danger: {})", name, danger_level.unwrap_or(0)));
+                }
+                Sighting::UnknownSignature => {
+                    app.add_message("- ??? Unknown signature");
+                }
+            }
+        }
+    }
+    app.add_message(&format!("☁️  Weather: {}", report.weather));
+    let (threat_low, threat_high) = report.threat_range;
+    let (scrap_low, scrap_high) = report.scrap_range;
+    if threat_low == threat_high {
+        app.add_warning(&format!("⚠️  Threat Level: {}%", threat_low));
+    } else {
+        app.add_warning(&format!("⚠️  Threat Level: {}-{}%", threat_low, threat_high));
+    }
+    if scrap_low == scrap_high {
+        app.add_message(&format!("💰 Scrap Value: {} credits", scrap_low));
+    } else {
+        app.add_message(&format!("💰 Estimated Scrap Value: {}-{} credits", scrap_low, scrap_high));
+    }
+    
+    for name in report.identified_names() {
+        if app.game_state.discover_creature(name) {
+            app.add_message(&format!("📖 New bestiary entry: {}", name));
+        }
+    }
+}
+
+fn handle_collect(app: &mut App) {
+    use crate::models::collect_credits::CollectCreditsEvent;
+    use crate::models::scan_logic::is_scan_expired;
+    use crate::utils::shortcut::format_name;
+    
+    if app.game_state.facility.is_some() {
+        app.add_warning("⚠️ You're inside the facility. GRAB scrap by hand instead.");
+        return;
+    }
+    
+    let location_key = app.game_state.ship.location.clone();
+    
+    if let Some(scan_data) = app.game_state.scan_data.get(&location_key) {
+        let scan_data = &scan_at_time(scan_data, app.game_state.landed_time.unwrap_or(LANDING_TIME));
+        if is_scan_expired(scan_data, app.game_state.day) {
+            app.add_warning(&format!("⚠️ Scan data for {} has expired. Use 'SCAN' first.", format_name(&location_key)));
+            return;
+        }
+        if scan_data.depleted {
+            app.add_warning(&format!("⚠️ {} has already been picked clean. Come back on another day.", format_name(&location_key)));
+            return;
+        }
+        
+        let penalty = player_weight_penalty(&app.game_state.players[0], app.game_state.carried_scrap());
+        let event = CollectCreditsEvent {
+            scan_data,
+            player_bonus: -(penalty as i32),
+            config: &app.game_data.collect_config,
+        };
+        
+        let chance = event.calculate_chance();
+        app.add_message(&format!("Chance to collect credits: {}%", chance));
+        if penalty > 0 {
+            app.add_message(&format!("🏋️ Your load costs you {}%. DROP something to move faster.", penalty));
+        }
+        app.add_message("Do you want to attempt collecting? (YES/NO)");
+        
+        app.input_mode = InputMode::Confirmation;
+        app.confirmation_type = Some(ConfirmationType::Collect);
+    } else {
+        app.add_warning(&format!("⚠️ No scan data available for {}. Use 'SCAN' first.", format_name(&location_key)));
+    }
+}
+
+/// Advances the landed clock and reports the ship leaving at midnight.
+fn spend_time(app: &mut App, minutes: u32) {
+    use crate::models::time_of_day::pass_time;
+    
+    if app.game_state.landed_time.is_some() {
+        for name in crate::models::items::drain_batteries(&mut app.game_state.players[0]) {
+            app.add_message(&format!("🔋 Your {}'s battery has run flat.", name));
+        }
+    }
+    
+    let was_inside = app.game_state.facility.is_some();
+    let quota = app.game_state.quota.clone();
+    if pass_time(&mut app.game_state, minutes) {
+        app.add_separator();
+        app.add_warning("🚨 It's midnight! The autopilot has taken off.");
+        if was_inside {
+            app.add_message("You were still inside the facility. Everything you carried is lost.");
+        }
+        app.add_message(&format!("The ship returns to the Company. Day {} begins.", app.game_state.day));
+        report_quota(app, &quota);
+        app.add_separator();
+    }
+}
+
+/// Announces a new quota if the deadline of `previous` has just passed.
+fn report_quota(app: &mut App, previous: &crate::models::types::Quota) {
+    let quota = app.game_state.quota.clone();
+    if quota.deadline_day == previous.deadline_day {
+        return;
+    }
+    if previous.is_met() {
+        app.add_success(&format!("✅ Quota met: {}/{} credits.", previous.fulfilled, previous.target));
+    } else {
+        app.add_warning(&format!("⚠️ Quota missed: {}/{} credits. The Company is not pleased.", previous.fulfilled, previous.target));
+    }
+    app.add_message(&format!("📈 New quota: {} credits by the end of day {}.", quota.target, quota.deadline_day));
+}
+
+fn handle_enter_facility(app: &mut App) {
+    use crate::models::facility::Facility;
+    use crate::models::scan_logic::is_scan_expired;
+    use crate::models::time_of_day::FACILITY_DOOR_MINUTES;
+    use crate::utils::shortcut::format_name;
+    
+    if app.game_state.facility.is_some() {
+        app.add_message("You're already inside the facility.");
+        return;
+    }
+    let Some(now) = app.game_state.landed_time else {
+        app.add_warning("⚠️ There's no facility here. Land on a moon first.");
+        return;
+    };
+    
+    let location_key = app.game_state.ship.location.clone();
+    let scan_data = match app.game_state.scan_data.get(&location_key) {
+        Some(scan_data) if !is_scan_expired(scan_data, app.game_state.day) => scan_data,
+        _ => {
+            app.add_warning(&format!("⚠️ No scan data available for {}. Use 'SCAN' first.", format_name(&location_key)));
+            return;
+        }
+    };
+    if scan_data.depleted {
+        app.add_warning(&format!("⚠️ {} has already been picked clean. Come back on another day.", format_name(&location_key)));
+        return;
+    }
+    
+    app.game_state.facility = Some(Facility::generate(&location_key, scan_data, now));
+    app.add_separator();
+    app.add_message(&format!("🚪 You push open the heavy door of the {} facility.", format_name(&location_key)));
+    app.add_message("Commands: LOOK, MOVE [room], GRAB [scrap], EXIT (from the Main Entrance)");
+    spend_time(app, FACILITY_DOOR_MINUTES);
+    handle_look(app);
+}
+
+fn handle_look(app: &mut App) {
+    let Some(facility) = app.game_state.facility.clone() else {
+        app.add_warning("⚠️ You're not inside a facility. Use 'ENTER FACILITY' first.");
+        return;
+    };
+    
+    let room = facility.current();
+    let exits: Vec<&str> = facility.exits().iter().map(|r| r.name.as_str()).collect();
+    
+    app.add_separator();
+    app.add_message(&format!("📍 {}", room.name));
+    if room.scrap.is_empty() {
+        app.add_message("Nothing of value here.");
+    } else {
+        app.add_message("You spot:");
+        for scrap in &room.scrap {
+            app.add_message(&format!("- {} ({} credits, {} lb)", scrap.name, scrap.value, scrap.weight));
+        }
+    }
+    for monster in &room.monsters {
+        app.add_warning(&format!("⚠️ A {} is here!", monster.name));
+    }
+    app.add_message(&format!("Exits: {}", exits.join(", ")));
+    if !facility.carried.is_empty() {
+        app.add_message(&format!("🎒 Carrying {} scrap worth {} credits.", facility.carried.len(), facility.carried_value()));
+    }
+    app.add_separator();
+}
+
+fn handle_move(app: &mut App, room_name: &str) {
+    use crate::models::time_of_day::MOVE_MINUTES;
+    
+    let Some(facility) = app.game_state.facility.as_mut() else {
+        app.add_warning("⚠️ You're not inside a facility. Use 'ENTER FACILITY' first.");
+        return;
+    };
+    
+    match facility.move_to(room_name) {
+        Ok(room) => {
+            let message = format!("You make your way to the {}...", room.name);
+            app.add_message(&message);
+            spend_time(app, MOVE_MINUTES);
+            if app.game_state.facility.is_some() {
+                handle_look(app);
+                check_engagement(app);
+            }
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+        }
+    }
+}
+
+/// Commands that still make sense while a creature has the operator cornered.
+fn allowed_in_encounter(command: &str) -> bool {
+    matches!(command, "RUN" | "HIDE" | "LOOK" | "INVENTORY" | "INV" | "HELP" | "BESTIARY" | "SAVE" | "QUIT")
+        || command.starts_with("USE ")
+        || command.starts_with("BESTIARY ")
+        || command.starts_with("LOG ")
+        || command.starts_with("THEME")
+}
+
+fn check_engagement(app: &mut App) {
+    use crate::models::encounter;
+    
+    if !encounter::check_engagement(&mut app.game_state, &mut rand::rng()) {
+        return;
+    }
+    if let Some(encounter) = &app.game_state.encounter {
+        let name = encounter.monster.name.clone();
+        let behavior = encounter.monster.behavior.clone();
+        app.game_state.discover_creature(&name);
+        app.add_separator();
+        app.add_warning(&format!("🚨 The {} has noticed you! ({})", name, behavior));
+        app.add_message("USE [item], RUN or HIDE.");
+        app.add_separator();
+    }
+}
+
+fn handle_encounter(app: &mut App, action: EncounterAction) {
+    use crate::models::encounter::{resolve, Outcome};
+    
+    let resolution = match resolve(&mut app.game_state, &action, &mut rand::rng()) {
+        Ok(resolution) => resolution,
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+            return;
+        }
+    };
+    let monster = &resolution.monster;
+    
+    if let Some(item) = &resolution.item_failed {
+        app.add_warning(&format!("⚠️ The {} clicks uselessly. Its battery is too low.", item));
+    }
+    match resolution.outcome {
+        Outcome::Stunned => {
+            app.add_success(&format!("✨ The {} is stunned! It won't bother you again this visit.", monster));
+        }
+        Outcome::DrivenOff => {
+            app.add_success(&format!("✨ You fight the {} off. It retreats into the dark.", monster));
+        }
+        Outcome::Escaped => {
+            let room = resolution.room.clone().unwrap_or_default();
+            app.add_message(&format!("💨 You outrun the {} and duck into the {}.", monster, room));
+        }
+        Outcome::Hidden => {
+            app.add_message(&format!("🤫 You hold your breath. The {} loses interest and wanders off.", monster));
+        }
+        Outcome::Hurt => {
+            let hp = app.game_state.players.first().map_or(0, |p| p.hp);
+            app.add_message(&format!("💥 The {} hits you for {} damage! HP: {}", monster, resolution.damage, hp));
+        }
+        Outcome::Died => {
+            app.add_separator();
+            app.add_error(&format!("☠️ The {} got you. Everything goes dark...", monster));
+            app.add_message("The Company retrieval team drags you back to the ship. The scrap you carried is lost.");
+            app.add_warning(&format!("❤️ You come to with {} HP.", crate::models::encounter::REVIVE_HP));
+            app.add_separator();
+        }
+    }
+    if let Some(item) = &resolution.item_spent {
+        app.add_message(&format!("Your {} is used up.", item));
+    }
+    if resolution.outcome == Outcome::Escaped {
+        handle_look(app);
+        check_engagement(app);
+    }
+}
+
+fn handle_use(app: &mut App, item_name: &str) {
+    use crate::models::items::use_item;
+    
+    if app.game_state.encounter.is_some() {
+        handle_encounter(app, EncounterAction::Use(item_name.to_string()));
+        return;
+    }
+    
+    match use_item(&mut app.game_state.players[0], item_name) {
+        Ok(used) => {
+            app.add_message(&format!("You use the {}.", used.name));
+            if used.spent {
+                app.add_message(&format!("Your {} is used up.", used.name));
+            } else {
+                if let Some(uses) = used.uses_remaining {
+                    app.add_message(&format!("  Uses left: {}", uses));
+                }
+                if let Some(charge) = used.charge {
+                    app.add_message(&format!("  🔋 Charge: {}%", charge));
+                }
+            }
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+        }
+    }
+}
+
+/// The inventory's spelling of an item the player typed in any case.
+fn carried_name(app: &App, item_name: &str) -> String {
+    app.game_state.players[0]
+        .inventory
+        .iter()
+        .find(|i| i.name.eq_ignore_ascii_case(item_name))
+        .map_or_else(|| item_name.to_string(), |i| i.name.clone())
+}
+
+fn handle_equip(app: &mut App, item_name: &str) {
+    use crate::models::items::equip;
+    
+    match equip(&mut app.game_state.players[0], item_name) {
+        Ok(previous) => {
+            if let Some(previous) = previous {
+                app.add_message(&format!("You put away the {}.", previous));
+            }
+            app.add_success(&format!("✅ {} equipped.", carried_name(app, item_name)));
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+        }
+    }
+}
+
+fn handle_drop(app: &mut App, item_name: &str) {
+    use crate::models::items::drop_item;
+    
+    match drop_item(&mut app.game_state.players[0], item_name) {
+        Ok(item) => {
+            app.add_message(&format!("You drop the {}. It's gone for good.", item.name));
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+        }
+    }
+}
+
+fn handle_charge(app: &mut App, item_name: &str) {
+    use crate::models::items::{recharge, FULL_CHARGE};
+    
+    if app.game_state.facility.is_some() {
+        app.add_warning("⚠️ The charging station is on the ship. Leave the facility first.");
+        return;
+    }
+    
+    match recharge(&mut app.game_state.players[0], item_name) {
+        Ok(before) => {
+            app.add_message(&format!("🔋 Charged {} from {}% to {}%.", carried_name(app, item_name), before, FULL_CHARGE));
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+        }
+    }
+}
+
+fn handle_grab(app: &mut App, scrap_name: &str) {
+    use crate::models::time_of_day::GRAB_MINUTES;
+    
+    let Some(facility) = app.game_state.facility.as_mut() else {
+        app.add_warning("⚠️ You're not inside a facility. Use 'ENTER FACILITY' first.");
+        return;
+    };
+    
+    match facility.grab(scrap_name) {
+        Ok(scrap) => {
+            app.add_success(&format!("✨ You pick up the {} ({} credits).", scrap.name, scrap.value));
+            spend_time(app, GRAB_MINUTES);
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+        }
+    }
+}
+
+fn handle_exit_facility(app: &mut App) {
+    use crate::models::time_of_day::FACILITY_DOOR_MINUTES;
+    
+    let Some(facility) = app.game_state.facility.take() else {
+        return;
+    };
+    if !facility.at_entrance() {
+        app.add_warning("⚠️ You can only leave through the Main Entrance.");
+        app.game_state.facility = Some(facility);
+        return;
+    }
+    
+    let carried_value = facility.carried_value();
+    let carried_count = facility.carried.len();
+    app.game_state.ship.scrap.extend(facility.carried);
+    
+    // The building has been explored; the scan now shows what was really there
+    if let Some(scan_data) = app.game_state.scan_data.get_mut(&facility.moon) {
+        scan_data.depleted = true;
+        scan_data.revealed = true;
+    }
+    
+    app.add_separator();
+    app.add_message("🚪 You step out of the facility and head back to the ship.");
+    app.add_message(&format!("Brought aboard {} scrap worth {} credits. Sell it at the Company.", carried_count, carried_value));
+    app.add_separator();
+    spend_time(app, FACILITY_DOOR_MINUTES);
+}
+
+fn handle_wait(app: &mut App) {
+    use crate::models::time_of_day::format_clock;
+    
+    if app.game_state.landed_time.is_none() {
+        app.add_warning("⚠️ There's nothing to wait for while in orbit. Land on a moon first.");
+        return;
+    }
+    
+    app.add_message(&format!("You wait for {} minutes...", WAIT_MINUTES));
+    spend_time(app, WAIT_MINUTES);
+    if let Some(now) = app.game_state.landed_time {
+        app.add_message(&format!("🕒 Local time: {}", format_clock(now)));
+    }
+}
+
+fn handle_monsters(app: &mut App) {
+    use crate::utils::shortcut::format_name;
+    
+    let bestiary = app.game_data.bestiary();
+    
+    let discovered = bestiary.discovered(&app.game_state.discovered_creatures);
+    
+    app.add_separator();
+    app.add_heading(&format!("Discovered creatures: {}/{}", discovered.len(), bestiary.monsters.len()));
+    app.add_separator();
+    if discovered.is_empty() {
+        app.add_message("No creatures discovered yet. SCAN a moon to identify its wildlife.");
+    } else {
+        for monster in discovered {
+            app.add_message(&format!(
+                "- {}: {}",
+                format_name(&monster.name),
+                monster.notes.as_deref().unwrap_or("No description available.")
+            ));
+        }
+        app.add_message("Use 'BESTIARY [name]' to read a full entry.");
+    }
+    app.add_separator();
+}
+
+fn handle_monster_page(app: &mut App, name: &str) {
+    use crate::utils::shortcut::format_name;
+    
+    let bestiary = app.game_data.bestiary();
+    
+    let Some(monster) = bestiary.find(name) else {
+        app.add_message(&format!("'{}' has no bestiary entry.", format_name(name)));
+        return;
+    };
+    
+    if !app.game_state.has_discovered(&monster.name) {
+        app.add_separator();
+        app.add_warning("🔒 DATA LOCKED: this creature has not been discovered yet.");
+        app.add_separator();
+        return;
+    }
+    
+    let moons: Vec<String> = monster.moons.iter().map(|m| format_name(m)).collect();
+    
+    app.add_separator();
+    app.add_heading(&format!("📖 {}", monster.name.to_uppercase()));
+    app.add_separator();
+    app.add_message(&format!("Entity type:     {}", monster.entity_type));
+    app.add_message(&format!("Behavior:        {}", monster.behavior));
+    app.add_message(&format!(
+        "Danger level:    {}",
+        monster.danger_level.map(|d| d.to_string()).unwrap_or_else(|| "Unknown".to_string())
+    ));
+    app.add_message(&format!("Power level:     {}", monster.power_level));
+    app.add_message(&format!("Speed:           {}", monster.speed.as_deref().unwrap_or("Unknown")));
+    app.add_message(&format!("Spawn condition: {}", monster.spawn_condition));
+    app.add_message(&format!("Sighted on:      {}", moons.join(", ")));
+    if let Some(notes) = &monster.notes {
+        app.add_message(&format!("📝 {}", notes));
+    }
+    app.add_separator();
+}
+
+fn handle_mods(app: &mut App) {
+    let report = app.mod_report.clone();
+    
+    app.add_separator();
+    app.add_heading(&format!("Active mod packs: {}", report.packs.len()));
+    app.add_separator();
+    if report.packs.is_empty() {
+        app.add_message(&format!(
+            "No mods loaded. Drop .json or .toml packs into '{}' and restart.",
+            crate::data::mods::mods_dir().display()
+        ));
+    }
+    for pack in &report.packs {
+        let version = pack.version.as_deref().map(|v| format!(" v{}", v)).unwrap_or_default();
+        app.add_message(&format!(
+            "- {}{}: {} moons, {} items, {} creatures",
+            pack.name, version, pack.moons, pack.store_items, pack.bestiary
+        ));
+    }
+    if !report.conflicts.is_empty() {
+        app.add_message("Conflicts (later packs win):");
+        for conflict in &report.conflicts {
+            app.add_message(&format!("  • {}", conflict));
+        }
+    }
+    if !report.errors.is_empty() {
+        app.add_warning("⚠️ Problems:");
+        for error in &report.errors {
+            app.add_message(&format!("  • {}", error));
+        }
+    }
+    app.add_separator();
+}
+
+fn handle_location(app: &mut App) {
+    use crate::utils::shortcut::format_name;
+    app.add_separator();
+    app.add_heading(&format!("Your current location is: {}", format_name(&app.game_state.ship.location)));
+    app.add_separator();
+}
+
+fn handle_help(app: &mut App) {
+    app.add_separator();
+    app.add_heading("Commands available:");
+    app.add_separator();
+    app.add_message("MOONS            - Lists visitable planets");
+    app.add_message("GO TO [moon]     - Travel to a planet");
+    app.add_message("LOCATION         - Show your current location");
     app.add_message("STORE            - Show the Store Items");
     app.add_message("SCAN             - Scan the environment");
+    app.add_message("SCAN DETAIL      - Show how the threat level is made up");
+    app.add_message(&format!("RESCAN           - Force a fresh scan ({} credits)", crate::models::scan_logic::RESCAN_COST));
     app.add_message("COLLECT          - Try to collect scrap credits");
-    app.add_message("BESTIARY         - Show scannable creatures");
-    app.add_message("BUY [item]       - Buy an item");
+    app.add_message("WAIT             - Let an hour pass on the moon");
+    app.add_message("ENTER FACILITY   - Explore the moon's facility room by room");
+    app.add_message("LOOK / MOVE [room] / GRAB [scrap] / EXIT - Inside the facility");
+    app.add_message("USE [item]       - Use an item (or fight back in an encounter)");
+    app.add_message("RUN / HIDE       - Escape a creature that has engaged you");
+    app.add_message("EQUIP [item]     - Hold an item in your hand or wear it");
+    app.add_message("DROP [item]      - Throw an item away");
+    app.add_message("TAKE [item]      - Pick up an item from the ship's storage");
+    app.add_message("CHARGE [item]    - Recharge a battery item aboard the ship");
+    app.add_message("BESTIARY         - Show discovered creatures");
+    app.add_message("BESTIARY [name]  - Read a creature's full entry");
+    app.add_message("BUY [n] [item]   - Buy one or more of an item");
+    app.add_message("CART ADD [n] [item] - Put items in your cart");
+    app.add_message("CART / CART CLEAR   - Show or empty your cart");
+    app.add_message("CHECKOUT         - Buy everything in your cart");
+    app.add_message("SELL [item]      - Sell an item or scrap back (Company only)");
+    app.add_message("SELL SCRAP       - Sell all scrap aboard at today's buy rate");
+    app.add_message("LEDGER [filters] - Credit history (DAY n, kind, INCOME, SPENDING)");
+    app.add_message("LOG FILTER [kind|ALL] - Only show errors, warnings, commands...");
+    app.add_message("LOG EXPORT [file] - Save the message log to a text file");
+    app.add_message("LOG SCROLLBACK [n] - Show or set how many log entries are kept");
+    app.add_message("/[text]          - Search the log (n/N: older/newer match)");
+    app.add_message("THEME [name]     - List colour themes or switch to one");
+    app.add_message("EFFECTS [ON|OFF] - Typewriter output and boot animation");
     app.add_message("INVENTORY        - Show your inventory");
+    app.add_message("MODS             - List active mod packs");
     app.add_message("SAVE             - Save the game state");
     app.add_message("LOAD             - Load the game state");
     app.add_message("NEW GAME         - Delete the game state");
     app.add_message("HELP             - Show this help");
     app.add_message("QUIT/EXIT        - Exit the game");
-    app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    app.add_separator();
 }
 
 fn handle_go_to(app: &mut App, moon: &str) {
-    use crate::models::lists::MOONS;
     use crate::utils::shortcut::format_name;
     
-    if MOONS.iter().any(|m| m.eq_ignore_ascii_case(moon)) {
+    if app.game_state.facility.is_some() {
+        app.add_warning("⚠️ You're still inside the facility. Return to the Main Entrance and EXIT first.");
+        return;
+    }
+    
+    if app.game_data.is_known_moon(moon) {
+        let leaving_moon = !commands_fn::is_at_company(&app.game_state)
+            && !app.game_state.ship.location.eq_ignore_ascii_case(moon);
+        if leaving_moon {
+            let quota = app.game_state.quota.clone();
+            app.game_state.advance_day();
+            app.add_message(&format!("🌅 The ship leaves orbit. Day {} begins.", app.game_state.day));
+            report_quota(app, &quota);
+        }
+        
         app.game_state.ship.location = moon.to_string().to_uppercase();
+        if commands_fn::is_at_company(&app.game_state) {
+            app.game_state.landed_time = None;
+        } else if leaving_moon || app.game_state.landed_time.is_none() {
+            crate::models::time_of_day::land(&mut app.game_state);
+        }
         app.add_message(&format!("Journey to {} underway...", format_name(&app.game_state.ship.location)));
         handle_location(app);
     } else {
@@ -481,64 +1566,443 @@ fn handle_go_to(app: &mut App, moon: &str) {
 }
 
 fn handle_buy(app: &mut App, cmd: &str) {
-    use crate::models::lists::STORE_ITEMS;
+    use crate::models::store::{checkout, parse_order, CartLine};
     use crate::utils::shortcut::format_name;
     
-    let item_name = cmd.trim_start_matches("BUY ").trim();
+    let (quantity, item_name) = match parse_order(cmd.trim_start_matches("BUY ")) {
+        Ok(order) => order,
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+            return;
+        }
+    };
     
-    if let Some(item) = STORE_ITEMS.iter().find(|i| i.name.eq_ignore_ascii_case(item_name)) {
-        let player_credits = app.game_state.players[0].credits;
-        let item_price = item.price;
-        let item_name_formatted = format_name(&item.name);
-        
-        if player_credits >= item_price {
-            app.game_state.players[0].credits -= item_price;
-            app.game_state.players[0].inventory.push(item.clone());
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            app.add_message(&format!("✨ You have purchased '{}' for {} credits.", item_name_formatted, item_price));
-            app.add_message(&format!("Your remaining credits: {}", app.game_state.players[0].credits));
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        } else {
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            app.add_message(&format!("⚠️ Not enough credits to purchase '{}'.", item_name_formatted));
-            app.add_message(&format!("You need {} credits, but you have only {}.", item_price, player_credits));
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    let Some(item) = app.game_data.find_item(item_name).cloned() else {
+        app.add_separator();
+        app.add_warning(&format!("'{}' item not available.", format_name(item_name)));
+        app.add_separator();
+        return;
+    };
+    let item_name_formatted = format_name(&item.name);
+    let order = [CartLine { item: app.prices().priced(&item), quantity }];
+    
+    app.add_separator();
+    let stored = app.game_state.ship.storage.len();
+    let game_state = &mut app.game_state;
+    let result = checkout(&mut game_state.players[0], &mut game_state.ship.storage, &order);
+    if let Ok(spent) = result {
+        let note = format!("{} x {}", quantity, item_name_formatted);
+        app.game_state.record_transaction(TransactionKind::Purchase, -(spent as i64), &note);
+    }
+    match result {
+        Ok(spent) if quantity == 1 => {
+            app.add_success(&format!("✨ You have purchased '{}' for {} credits.", item_name_formatted, spent));
+        }
+        Ok(spent) => {
+            app.add_success(&format!("✨ You have purchased {} x '{}' for {} credits.", quantity, item_name_formatted, spent));
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ Could not purchase '{}'.", item_name_formatted));
+            app.add_message(&e.to_string());
+        }
+    }
+    report_delivery(app, stored);
+    app.add_message(&format!("Your remaining credits: {}", app.game_state.players[0].credits));
+    app.add_separator();
+}
+
+/// Tells the operator about purchases that went to the ship's storage
+/// because their slots were full. `stored` is the storage size before.
+fn report_delivery(app: &mut App, stored: usize) {
+    let delivered = app.game_state.ship.storage.len() - stored;
+    if delivered > 0 {
+        app.add_message(&format!("📦 {} item(s) didn't fit and were delivered to the ship. TAKE [item] to pick them up.", delivered));
+    }
+}
+
+fn handle_take(app: &mut App, item_name: &str) {
+    use crate::models::items::take_item;
+    
+    if app.game_state.facility.is_some() {
+        app.add_warning("⚠️ The ship's storage is on the ship. Leave the facility first.");
+        return;
+    }
+    
+    let game_state = &mut app.game_state;
+    match take_item(&mut game_state.players[0], &mut game_state.ship.storage, item_name) {
+        Ok(name) => app.add_message(&format!("You take the {} from the ship's storage.", name)),
+        Err(e) => app.add_warning(&format!("⚠️ {}", e)),
+    }
+}
+
+fn handle_cart_add(app: &mut App, args: &str) {
+    use crate::models::store::{add_to_cart, parse_order, MAX_QUANTITY};
+    use crate::utils::shortcut::format_name;
+    
+    let (quantity, item_name) = match parse_order(args) {
+        Ok(order) => order,
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+            return;
+        }
+    };
+    let Some(item) = app.game_data.find_item(item_name).cloned() else {
+        app.add_message(&format!("'{}' item not available.", format_name(item_name)));
+        return;
+    };
+    
+    let before = app.game_state.cart.iter().find(|line| line.item.name == item.name).map_or(0, |line| line.quantity);
+    let name = item.name.clone();
+    let item = app.prices().priced(&item);
+    let in_cart = add_to_cart(&mut app.game_state.cart, item, quantity);
+    let added = in_cart - before;
+    
+    if added < quantity {
+        app.add_warning(&format!(
+            "⚠️ A cart holds at most {} x {}; only {} more added.",
+            MAX_QUANTITY, name, added
+        ));
+    }
+    if added > 0 {
+        app.add_message(&format!("🛒 Added {} x {} to your cart ({} in cart).", added, name, in_cart));
+    }
+}
+
+/// Sale prices change from day to day, so a cart is always shown and paid
+/// at today's prices.
+fn reprice_cart(app: &mut App) {
+    let prices = app.prices();
+    for line in app.game_state.cart.iter_mut() {
+        if let Some(base) = app.game_data.find_item(&line.item.name) {
+            line.item = prices.priced(base);
         }
+    }
+}
+
+fn show_cart(app: &mut App) {
+    use crate::models::store::cart_total;
+    
+    reprice_cart(app);
+    let cart = app.game_state.cart.clone();
+    let total = cart_total(&cart);
+    let credits = app.game_state.players[0].credits;
+    
+    for line in &cart {
+        app.add_message(&format!(
+            "{:>3} x {:<16} {:>5} credits",
+            line.quantity,
+            line.item.name,
+            line.subtotal()
+        ));
+    }
+    app.add_message(&format!("Total: {} credits", total));
+    if total <= credits {
+        app.add_message(&format!("Credits after checkout: {}", credits - total));
     } else {
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        app.add_message(&format!("'{}' item not available.", format_name(&item_name)));
-        app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        app.add_warning(&format!("⚠️ You are {} credits short.", total - credits));
     }
 }
 
-async fn handle_save(app: &mut App) {
-    use crate::data::mongodb;
+fn handle_cart(app: &mut App) {
+    app.add_separator();
+    app.add_heading("🛒 Your cart:");
+    app.add_separator();
+    if app.game_state.cart.is_empty() {
+        app.add_message("Your cart is empty. Use 'CART ADD [n] [item]' to fill it.");
+    } else {
+        show_cart(app);
+        app.add_message("Type CHECKOUT to buy everything, or CART CLEAR to empty the cart.");
+    }
+    app.add_separator();
+}
+
+fn handle_checkout(app: &mut App) {
+    if app.game_state.cart.is_empty() {
+        app.add_warning(&format!("⚠️ {}", crate::models::store::StoreError::EmptyCart));
+        return;
+    }
+    
+    app.add_separator();
+    show_cart(app);
+    app.add_separator();
+    app.request_confirmation(ConfirmationType::Checkout, "Buy everything in your cart? (YES/NO)");
+}
+
+fn handle_sell(app: &mut App, name: &str) {
+    use crate::models::store::{buy_rate, sell_item, sell_scrap};
+    
+    if !commands_fn::is_at_company(&app.game_state) {
+        app.add_warning("⚠️ You can only sell at the Company. Use 'GO TO COMPANY' first.");
+        return;
+    }
+    
+    let carried = app.game_state.players[0].inventory.iter().any(|i| i.name.eq_ignore_ascii_case(name));
+    let result = if name == "SCRAP" {
+        sell_scrap(&mut app.game_state, None)
+    } else if carried {
+        let config = app.game_data.store_config.clone();
+        sell_item(&mut app.game_state.players[0], name, &config)
+    } else {
+        sell_scrap(&mut app.game_state, Some(name))
+    };
+    
+    let receipt = match result {
+        Ok(receipt) => {
+            let kind = if carried { TransactionKind::Refund } else { TransactionKind::ScrapSale };
+            let names: Vec<&str> = receipt.lines.iter().map(|(name, _)| name.as_str()).collect();
+            app.game_state.record_transaction(kind, receipt.total as i64, &names.join(", "));
+            receipt
+        }
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}", e));
+            return;
+        }
+    };
+    
+    app.add_separator();
+    app.add_heading("🧾 THE COMPANY - RECEIPT");
+    app.add_separator();
+    for (line, value) in &receipt.lines {
+        app.add_message(&format!("{:<24} {:>6} credits", line, value));
+    }
+    app.add_message(&format!("{:<24} {:>6} credits", "TOTAL", receipt.total));
+    if !carried {
+        let quota = app.game_state.quota.clone();
+        app.add_message(&format!("Buy rate today: {}%", buy_rate(&quota, app.game_state.day)));
+        app.add_message(&format!("Quota: {}/{} credits", quota.fulfilled, quota.target));
+    }
+    app.add_message(&format!("Your balance: {} credits", receipt.balance));
+    app.add_separator();
+}
+
+fn handle_ledger(app: &mut App, args: &str) {
+    use crate::models::ledger::{daily_summary, LedgerFilter};
+    
+    let filter = match LedgerFilter::parse(args) {
+        Ok(filter) => filter,
+        Err(e) => {
+            app.add_warning(&format!("⚠️ {}. Try LEDGER [DAY n] [PURCHASE|REFUND|SCRAP|COLLECT|RESCAN] [INCOME|SPENDING]", e));
+            return;
+        }
+    };
+    let ledger = app.game_state.ledger.clone();
+    let entries = filter.apply(&ledger);
+    
+    app.add_separator();
+    app.add_heading("📒 Credit ledger:");
+    app.add_separator();
+    if entries.is_empty() {
+        app.add_message("No transactions recorded.");
+        app.add_separator();
+        return;
+    }
+    
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.day.to_string(),
+                entry.turn.to_string(),
+                entry.location.clone(),
+                entry.kind.to_string(),
+                format!("{:+}", entry.amount),
+                entry.balance.to_string(),
+                entry.note.clone(),
+            ]
+        })
+        .collect();
+    app.add_table(&["DAY", "TURN", "LOCATION", "KIND", "AMOUNT", "BALANCE", "NOTE"], rows);
+    
+    app.add_separator();
+    app.add_message("Daily summary:");
+    for day in daily_summary(&entries) {
+        app.add_message(&format!(
+            "Day {:<3} income {:>6}  spending {:>6}  net {:>+7}",
+            day.day, day.income, day.spending, day.net()
+        ));
+    }
+    app.add_separator();
+}
+
+fn handle_effects(app: &mut App, setting: &str) {
+    match setting {
+        "" => {
+            let state = if app.typewriter.enabled { "ON" } else { "OFF" };
+            app.add_message(&format!("Terminal effects are {}. Use EFFECTS ON or EFFECTS OFF.", state));
+            app.add_message("Set TERMINAL_COMPANY_EFFECTS=off to start without them.");
+        }
+        "ON" => {
+            app.set_effects(true);
+            app.add_success("✨ Terminal effects on.");
+        }
+        "OFF" => {
+            app.set_effects(false);
+            app.add_success("✨ Terminal effects off.");
+        }
+        _ => app.add_warning("⚠️ Usage: EFFECTS [ON|OFF]"),
+    }
+}
+
+fn handle_theme(app: &mut App, name: &str) {
+    if name.is_empty() {
+        app.add_separator();
+        app.add_heading("🎨 Colour themes:");
+        app.add_separator();
+        let current = app.theme.name.clone();
+        let names: Vec<String> = app.themes.themes.iter().map(|t| t.name.clone()).collect();
+        for theme in names {
+            let marker = if theme == current { "▶" } else { " " };
+            app.add_message(&format!("{} {}", marker, theme));
+        }
+        app.add_message("Use THEME [name] to switch. Custom themes go in themes.toml.");
+        app.add_separator();
+        return;
+    }
     
-    match mongodb::save_game_state(&app.db_client, &app.game_state).await {
-        Ok(_) => app.add_message("✅ Game state saved successfully."),
-        Err(e) => app.add_message(&format!("⚠️ Failed to save game state: {}", e)),
+    match app.themes.find(name).cloned() {
+        Some(theme) => {
+            app.add_success(&format!("✨ Theme set to {}.", theme.name));
+            app.theme = theme;
+        }
+        None => app.add_warning(&format!("⚠️ Unknown theme '{}'. Type THEME to see them all.", name.to_lowercase())),
     }
 }
 
-async fn handle_load(app: &mut App) {
+/// Default file for `LOG EXPORT`, relative to the working directory.
+const LOG_EXPORT_FILE: &str = "terminal-company-log.txt";
+
+fn handle_log(app: &mut App, args: &str, raw: &str) {
+    use crate::ui::log::{LogKind, MIN_SCROLLBACK};
+    use crate::utils::shortcut::skip_words;
+    
+    let (action, rest) = args.split_once(' ').unwrap_or((args, ""));
+    let rest = rest.trim();
+    match action {
+        "FILTER" if rest.is_empty() || rest == "ALL" => {
+            app.log_filter = None;
+            app.scroll_to_bottom();
+            app.add_success("✨ Showing the whole log.");
+        }
+        "FILTER" => match LogKind::parse(rest) {
+            Some(kind) => {
+                app.log_filter = Some(kind);
+                app.scroll_to_bottom();
+                app.add_success(&format!("✨ Showing {} entries only. LOG FILTER ALL to reset.", kind.label().to_lowercase()));
+            }
+            None => {
+                let kinds: Vec<&str> = LogKind::ALL.iter().map(|k| k.label()).collect();
+                app.add_warning(&format!("⚠️ Unknown log kind '{}'. Try one of: {}, ALL", rest, kinds.join(", ")));
+            }
+        },
+        "EXPORT" => {
+            // The file name is taken as typed, not from the uppercased command.
+            let file = match skip_words(raw, 2) {
+                "" => LOG_EXPORT_FILE,
+                file => file,
+            };
+            match app.export_log(std::path::Path::new(&file)) {
+                Ok(count) => app.add_success(&format!("✅ Exported {} log entries to {}.", count, file)),
+                Err(e) => app.add_error(&format!("⚠️ Failed to export the log: {}", e)),
+            }
+        }
+        "SCROLLBACK" if rest.is_empty() => {
+            app.add_message(&format!(
+                "The log keeps the last {} entries ({} older ones dropped so far).",
+                app.scrollback, app.dropped_entries
+            ));
+        }
+        "SCROLLBACK" => match rest.parse::<usize>() {
+            Ok(limit) if limit >= MIN_SCROLLBACK => {
+                app.set_scrollback(limit);
+                app.add_success(&format!("✨ The log now keeps the last {} entries.", limit));
+            }
+            _ => app.add_warning(&format!("⚠️ Scrollback must be a number of at least {}.", MIN_SCROLLBACK)),
+        },
+        _ => app.add_warning("⚠️ Usage: LOG FILTER [kind|ALL], LOG EXPORT [file] or LOG SCROLLBACK [n]"),
+    }
+}
+
+fn handle_save(app: &mut App) {
+    use crate::data::mongodb;
+    use crate::ui::tasks::TaskResult;
+    
+    let client = app.db_client.clone();
+    let game_state = app.game_state.clone();
+    app.tasks.spawn("Saving game", crate::ui::bus::publisher(), async move {
+        TaskResult::Saved(mongodb::save_game_state(&client, &game_state).await.map_err(|e| e.to_string()))
+    });
+}
+
+fn handle_load(app: &mut App) {
+    use crate::data::mongodb;
+    use crate::ui::tasks::TaskResult;
+    
+    let client = app.db_client.clone();
+    app.tasks.spawn("Loading game", crate::ui::bus::publisher(), async move {
+        TaskResult::Loaded(mongodb::load_game_state(&client).await.map(|state| state.map(Box::new)).map_err(|e| e.to_string()))
+    });
+}
+
+/// Replaces the saved game with a fresh one for a new operator. The game
+/// in memory is only swapped once the database has the new one.
+fn start_new_game(app: &mut App, name: &str, role: &str) {
+    use crate::commands::registration::new_game_state;
     use crate::data::mongodb;
+    use crate::ui::tasks::TaskResult;
     
-    match mongodb::load_game_state(&app.db_client).await {
-        Ok(Some(state)) => {
-            app.game_state = state;
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            app.add_message("✅ Game state loaded successfully.");
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    app.add_message("Starting new game...");
+    let client = app.db_client.clone();
+    let game_state = new_game_state(name, role);
+    // Saving replaces the old game in one write, so cancelling never leaves
+    // the database without one.
+    app.tasks.spawn("Starting new game", crate::ui::bus::publisher(), async move {
+        let saved = mongodb::save_game_state(&client, &game_state).await.map_err(|e| e.to_string());
+        TaskResult::NewGame(saved.map(|()| Box::new(game_state)))
+    });
+}
+
+/// Applies an event published on the UI bus, e.g. by a background task.
+pub fn handle_ui_event(app: &mut App, event: crate::ui::bus::UiEvent) {
+    use crate::ui::bus::UiEvent;
+    
+    match event {
+        UiEvent::Log(entry) => app.push_log(entry),
+        UiEvent::TaskDone(done) => {
+            if app.tasks.finish(done.id) {
+                app.is_processing = false;
+                handle_task_result(app, done.result);
+            }
         }
-        Ok(None) => {
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            app.add_message("⚠️ No saved game state found.");
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    }
+}
+
+fn handle_task_result(app: &mut App, result: crate::ui::tasks::TaskResult) {
+    use crate::ui::tasks::TaskResult;
+    
+    match result {
+        TaskResult::Saved(Ok(())) => app.add_success("✅ Game state saved successfully."),
+        TaskResult::Saved(Err(e)) => app.add_error(&format!("⚠️ Failed to save game state: {}", e)),
+        TaskResult::Loaded(Ok(Some(state))) => {
+            app.game_state = *state;
+            app.add_separator();
+            app.add_success("✅ Game state loaded successfully.");
+            app.add_separator();
         }
-        Err(e) => {
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            app.add_message(&format!("⚠️ Error loading game state: {}", e));
-            app.add_message("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        TaskResult::Loaded(Ok(None)) => {
+            app.add_separator();
+            app.add_warning("⚠️ No saved game state found.");
+            app.add_separator();
+        }
+        TaskResult::Loaded(Err(e)) => {
+            app.add_separator();
+            app.add_error(&format!("⚠️ Error loading game state: {}", e));
+            app.add_separator();
+        }
+        TaskResult::NewGame(Ok(state)) => {
+            app.game_state = *state;
+            app.add_success("✨ New game started!");
+            app.add_message(&format!("Welcome, {}!", app.game_state.players[0].name));
         }
+        TaskResult::NewGame(Err(e)) => app.add_error(&format!("⚠️ Error starting new game: {}", e)),
     }
 }
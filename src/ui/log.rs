@@ -0,0 +1,242 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+pub const DEFAULT_SCROLLBACK: usize = 1000;
+pub const MIN_SCROLLBACK: usize = 100;
+
+/// Entries kept in the log, from `TERMINAL_COMPANY_SCROLLBACK` if set.
+pub fn scrollback_limit() -> usize {
+    std::env::var("TERMINAL_COMPANY_SCROLLBACK")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_SCROLLBACK)
+        .max(MIN_SCROLLBACK)
+}
+
+/// What a log line is, so the renderer can style it without looking at the
+/// text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogKind {
+    /// A command the player typed, echoed back.
+    Command,
+    Heading,
+    Info,
+    Warning,
+    Error,
+    Success,
+    Separator,
+    Table,
+}
+
+impl LogKind {
+    pub const ALL: [LogKind; 8] = [
+        LogKind::Command,
+        LogKind::Heading,
+        LogKind::Info,
+        LogKind::Warning,
+        LogKind::Error,
+        LogKind::Success,
+        LogKind::Separator,
+        LogKind::Table,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogKind::Command => "COMMAND",
+            LogKind::Heading => "HEADING",
+            LogKind::Info => "INFO",
+            LogKind::Warning => "WARNING",
+            LogKind::Error => "ERROR",
+            LogKind::Success => "SUCCESS",
+            LogKind::Separator => "SEPARATOR",
+            LogKind::Table => "TABLE",
+        }
+    }
+
+    /// Accepts the label or its plural, e.g. `ERRORS`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_uppercase();
+        let text = text.strip_suffix('S').filter(|t| *t != "SUCCES").unwrap_or(text.as_str());
+        Self::ALL.into_iter().find(|k| k.label() == text)
+    }
+}
+
+impl fmt::Display for LogKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.label())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Lays the table out in at most `width` columns. Columns take the width
+    /// of their widest cell; when that doesn't fit, the widest columns are
+    /// narrowed first and long cells are cut with an ellipsis.
+    pub fn layout(&self, width: usize) -> Vec<String> {
+        let columns = self.headers.len();
+        if columns == 0 {
+            return vec![];
+        }
+        let mut widths: Vec<usize> = (0..columns)
+            .map(|c| {
+                std::iter::once(&self.headers)
+                    .chain(&self.rows)
+                    .filter_map(|row| row.get(c))
+                    .map(|cell| cell.width())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let gaps = (columns - 1) * 2;
+        while widths.iter().sum::<usize>() + gaps > width {
+            let widest = (0..columns).max_by_key(|c| widths[*c]).unwrap_or(0);
+            if widths[widest] <= 3 {
+                break;
+            }
+            widths[widest] -= 1;
+        }
+
+        let render_row = |row: &Vec<String>| {
+            let cells: Vec<String> = (0..columns)
+                .map(|c| fit(row.get(c).map_or("", String::as_str), widths[c]))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        };
+
+        std::iter::once(render_row(&self.headers))
+            .chain(self.rows.iter().map(render_row))
+            .collect()
+    }
+}
+
+/// Columns `c` takes up in the terminal: two for emoji and other wide
+/// characters.
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Pads or cuts `text` to exactly `width` columns.
+fn fit(text: &str, width: usize) -> String {
+    let length = text.width();
+    if length <= width {
+        format!("{}{}", text, " ".repeat(width - length))
+    } else if width == 0 {
+        String::new()
+    } else {
+        let mut cut = String::new();
+        let mut used = 0;
+        for c in text.chars() {
+            if used + char_width(c) > width - 1 {
+                break;
+            }
+            used += char_width(c);
+            cut.push(c);
+        }
+        format!("{}…{}", cut, " ".repeat(width - 1 - used))
+    }
+}
+
+/// Breaks `text` into lines at most `width` columns wide, at spaces where
+/// possible. Always returns at least one line.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    if width == 0 || text.width() <= width {
+        return vec![text.to_string()];
+    }
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut used = 0;
+    for word in text.split(' ') {
+        let word_width = word.width();
+        if used > 0 && used + 1 + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            used = 0;
+        }
+        if used > 0 {
+            line.push(' ');
+            used += 1;
+        }
+        if used + word_width <= width {
+            line.push_str(word);
+            used += word_width;
+            continue;
+        }
+        for c in word.chars() {
+            if used > 0 && used + char_width(c) > width {
+                lines.push(std::mem::take(&mut line));
+                used = 0;
+            }
+            used += char_width(c);
+            line.push(c);
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub kind: LogKind,
+    pub text: String,
+    pub table: Option<Table>,
+    pub timestamp: SystemTime,
+    /// Command put in the input line when the entry is clicked.
+    pub action: Option<String>,
+}
+
+impl LogEntry {
+    pub fn new(kind: LogKind, text: &str) -> Self {
+        LogEntry {
+            kind,
+            text: text.to_string(),
+            table: None,
+            timestamp: SystemTime::now(),
+            action: None,
+        }
+    }
+
+    pub fn with_action(mut self, command: &str) -> Self {
+        self.action = Some(command.to_string());
+        self
+    }
+
+    pub fn table(headers: &[&str], rows: Vec<Vec<String>>) -> Self {
+        LogEntry {
+            table: Some(Table {
+                headers: headers.iter().map(|h| h.to_string()).collect(),
+                rows,
+            }),
+            ..LogEntry::new(LogKind::Table, "")
+        }
+    }
+
+    /// The entry as screen lines for a log pane `width` columns wide. Long
+    /// text wraps; tables are narrowed instead.
+    pub fn lines(&self, width: usize) -> Vec<String> {
+        match &self.table {
+            Some(table) => table.layout(width),
+            None => wrap(&self.text, width),
+        }
+    }
+
+    /// Time of day the entry was logged, as HH:MM:SS (UTC).
+    pub fn clock(&self) -> String {
+        let seconds = self.timestamp.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        format!("{:02}:{:02}:{:02}", seconds / 3600 % 24, seconds / 60 % 60, seconds % 60)
+    }
+
+    /// Plain-text form used by `LOG EXPORT`.
+    pub fn export(&self) -> String {
+        self.lines(usize::MAX)
+            .iter()
+            .map(|line| format!("[{}] {:<9} {}", self.clock(), self.kind, line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
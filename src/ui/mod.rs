@@ -1,5 +1,11 @@
 pub mod app;
+pub mod bus;
+pub mod clipboard;
 pub mod components;
+pub mod effects;
 pub mod event;
 pub mod inputs;
-pub mod output_capture;
+pub mod log;
+pub mod search;
+pub mod tasks;
+pub mod theme;
@@ -0,0 +1,66 @@
+use std::ops::Range;
+
+/// A `/` search through the log. Matches are log line indices as returned by
+/// `components::log_lines`, so they follow wrapping and filters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Search {
+    pub query: String,
+    /// The match the view is on.
+    pub current: Option<usize>,
+    /// True while the query is being typed; false while browsing matches.
+    pub editing: bool,
+    /// 1-based number of the current match, counted from the newest.
+    pub position: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    /// Towards older output (`n`).
+    Older,
+    /// Towards newer output (`N`).
+    Newer,
+}
+
+/// Byte ranges of every occurrence of `query` in `line`, ignoring ASCII case.
+pub fn match_ranges(line: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return vec![];
+    }
+    let haystack = line.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    haystack
+        .match_indices(&needle)
+        .map(|(start, found)| start..start + found.len())
+        .collect()
+}
+
+/// Indices of the lines containing `query`, oldest first.
+pub fn find_matches<'a>(lines: impl IntoIterator<Item = &'a str>, query: &str) -> Vec<usize> {
+    lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, line)| !match_ranges(line, query).is_empty())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The match after `current` in `direction`, wrapping around. With no
+/// current match, searching starts from the newest output.
+pub fn next_match(matches: &[usize], current: Option<usize>, direction: SearchDirection) -> Option<usize> {
+    let newest = *matches.last()?;
+    let oldest = *matches.first()?;
+    let Some(current) = current else {
+        return Some(newest);
+    };
+    match direction {
+        SearchDirection::Older => matches.iter().rev().find(|m| **m < current).copied().or(Some(newest)),
+        SearchDirection::Newer => matches.iter().find(|m| **m > current).copied().or(Some(oldest)),
+    }
+}
+
+/// Scroll offset (lines up from the bottom) that puts line `index` in the
+/// middle of a log pane `height` lines tall.
+pub fn centre_on(index: usize, total_lines: usize, height: usize) -> usize {
+    total_lines.saturating_sub(index + 1 + height / 2)
+}
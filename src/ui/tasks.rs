@@ -0,0 +1,101 @@
+use crate::models::types::GameState;
+use crate::ui::bus::{Publisher, UiEvent};
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// What a background command hands back to the UI when it finishes.
+#[derive(Debug, Clone)]
+pub enum TaskResult {
+    Saved(Result<(), String>),
+    Loaded(Result<Option<Box<GameState>>, String>),
+    /// The saved game was replaced by a fresh one for a new operator.
+    NewGame(Result<Box<GameState>, String>),
+}
+
+#[derive(Debug, Clone)]
+pub struct TaskDone {
+    pub id: u64,
+    pub result: TaskResult,
+}
+
+#[derive(Debug)]
+pub struct PendingTask {
+    pub id: u64,
+    pub label: String,
+    pub started: Instant,
+    handle: JoinHandle<()>,
+}
+
+impl PendingTask {
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+/// Runs slow commands (database round trips) off the input loop. Only one
+/// runs at a time; its result comes back over the UI event bus.
+#[derive(Debug, Default)]
+pub struct Tasks {
+    next_id: u64,
+    pending: Option<PendingTask>,
+}
+
+impl Tasks {
+    /// Starts `work` on the runtime. Must be called from inside a tokio
+    /// runtime. Returns the task id carried by its `TaskDone` event.
+    pub fn spawn<F>(&mut self, label: &str, publisher: Publisher, work: F) -> u64
+    where
+        F: Future<Output = TaskResult> + Send + 'static,
+    {
+        self.next_id += 1;
+        let id = self.next_id;
+        let handle = tokio::spawn(async move {
+            let result = work.await;
+            publisher.send(UiEvent::TaskDone(TaskDone { id, result }));
+        });
+        if let Some(previous) = self.pending.replace(PendingTask {
+            id,
+            label: label.to_string(),
+            started: Instant::now(),
+            handle,
+        }) {
+            previous.handle.abort();
+        }
+        id
+    }
+
+    pub fn pending(&self) -> Option<&PendingTask> {
+        self.pending.as_ref()
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Aborts the running task, if any, and returns its label. A result it
+    /// already sent is ignored by `finish`.
+    pub fn cancel(&mut self) -> Option<String> {
+        let task = self.pending.take()?;
+        task.handle.abort();
+        Some(task.label)
+    }
+
+    /// Marks task `id` as done. Returns false for results of tasks that were
+    /// cancelled or replaced, which the caller should drop.
+    pub fn finish(&mut self, id: u64) -> bool {
+        if self.pending.as_ref().is_some_and(|t| t.id == id) {
+            self.pending = None;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Spinner frame for a task that has been running for `elapsed`.
+pub fn spinner(elapsed: Duration) -> &'static str {
+    SPINNER[(elapsed.as_millis() / 100) as usize % SPINNER.len()]
+}
@@ -0,0 +1,278 @@
+use crate::derive_struct;
+use crate::ui::log::LogKind;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const DEFAULT_THEME_FILE: &str = "themes.toml";
+pub const DEFAULT_THEME: &str = "classic";
+pub const NO_COLOR_THEME: &str = "mono";
+
+/// Styles for every part of the TUI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub border: Style,
+    pub text: Style,
+    pub muted: Style,
+    /// Values in the header, e.g. credits and location.
+    pub accent: Style,
+    pub command: Style,
+    pub heading: Style,
+    pub warning: Style,
+    pub error: Style,
+    pub success: Style,
+}
+
+fn fg(color: Color) -> Style {
+    Style::default().fg(color)
+}
+
+impl Theme {
+    /// The green-on-black look the game has always had.
+    pub fn classic() -> Self {
+        Theme {
+            name: "classic".to_string(),
+            border: fg(Color::Green),
+            text: fg(Color::White),
+            muted: fg(Color::DarkGray),
+            accent: fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            command: fg(Color::Cyan),
+            heading: fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            warning: fg(Color::Yellow),
+            error: fg(Color::Red),
+            success: fg(Color::Green),
+        }
+    }
+
+    pub fn amber() -> Self {
+        let amber = Color::Rgb(255, 176, 0);
+        Theme {
+            name: "amber".to_string(),
+            border: fg(amber),
+            text: fg(amber),
+            muted: fg(Color::Rgb(150, 100, 0)),
+            accent: fg(amber).add_modifier(Modifier::BOLD),
+            command: fg(Color::Rgb(255, 210, 110)),
+            heading: fg(amber).add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            warning: fg(Color::Rgb(255, 220, 120)).add_modifier(Modifier::BOLD),
+            error: fg(Color::Rgb(255, 90, 40)).add_modifier(Modifier::BOLD),
+            success: fg(Color::Rgb(255, 200, 60)),
+        }
+    }
+
+    /// Dark colours that stay readable on a light background.
+    pub fn light() -> Self {
+        Theme {
+            name: "light".to_string(),
+            border: fg(Color::Blue),
+            text: fg(Color::Black),
+            muted: fg(Color::DarkGray),
+            accent: fg(Color::Blue).add_modifier(Modifier::BOLD),
+            command: fg(Color::Magenta),
+            heading: fg(Color::Blue).add_modifier(Modifier::BOLD),
+            warning: fg(Color::Rgb(160, 90, 0)),
+            error: fg(Color::Rgb(190, 0, 0)).add_modifier(Modifier::BOLD),
+            success: fg(Color::Rgb(0, 120, 0)),
+        }
+    }
+
+    /// Keeps the terminal's own foreground and marks problems with solid
+    /// backgrounds, so it works on dark and light terminals alike.
+    pub fn high_contrast() -> Self {
+        let plain = fg(Color::Reset);
+        Theme {
+            name: "high-contrast".to_string(),
+            border: plain.add_modifier(Modifier::BOLD),
+            text: plain,
+            muted: plain,
+            accent: plain.add_modifier(Modifier::BOLD),
+            command: plain.add_modifier(Modifier::BOLD),
+            heading: plain.add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            warning: Style::default().fg(Color::Black).bg(Color::Yellow),
+            error: Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD),
+            success: Style::default().fg(Color::Black).bg(Color::Green),
+        }
+    }
+
+    /// No colour at all, only bold, dim and reverse. Used when `NO_COLOR`
+    /// is set.
+    pub fn mono() -> Self {
+        let plain = Style::default();
+        Theme {
+            name: NO_COLOR_THEME.to_string(),
+            border: plain,
+            text: plain,
+            muted: plain.add_modifier(Modifier::DIM),
+            accent: plain.add_modifier(Modifier::BOLD),
+            command: plain.add_modifier(Modifier::BOLD),
+            heading: plain.add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            warning: plain.add_modifier(Modifier::BOLD),
+            error: plain.add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            success: plain,
+        }
+    }
+
+    pub fn built_in() -> Vec<Theme> {
+        vec![Theme::classic(), Theme::amber(), Theme::light(), Theme::high_contrast(), Theme::mono()]
+    }
+
+    pub fn log_style(&self, kind: LogKind) -> Style {
+        match kind {
+            LogKind::Command => self.command,
+            LogKind::Heading => self.heading,
+            LogKind::Warning => self.warning,
+            LogKind::Error => self.error,
+            LogKind::Success => self.success,
+            LogKind::Separator => self.muted,
+            LogKind::Info | LogKind::Table => self.text,
+        }
+    }
+}
+
+derive_struct! {
+/// A user theme as written in the theme file. Colours are names (`red`,
+/// `lightblue`), `#rrggbb` or a 256-colour index; unset ones come from `base`.
+pub struct ThemeSpec {
+    pub name: String,
+    #[serde(default)]
+    pub base: Option<String>,
+    #[serde(default)]
+    pub border: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub muted: Option<String>,
+    #[serde(default)]
+    pub accent: Option<String>,
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub heading: Option<String>,
+    #[serde(default)]
+    pub warning: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub success: Option<String>,
+}}
+
+derive_struct! {
+pub struct ThemeFile {
+    /// Theme to start with instead of `classic`.
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub themes: Vec<ThemeSpec>,
+}}
+
+/// Built-in themes plus any from the theme file.
+#[derive(Debug, Clone)]
+pub struct ThemeSet {
+    pub themes: Vec<Theme>,
+    pub default: String,
+    pub errors: Vec<String>,
+}
+
+impl Default for ThemeSet {
+    fn default() -> Self {
+        ThemeSet {
+            themes: Theme::built_in(),
+            default: DEFAULT_THEME.to_string(),
+            errors: vec![],
+        }
+    }
+}
+
+/// The theme file, overridable with `TERMINAL_COMPANY_THEMES`.
+pub fn themes_file() -> PathBuf {
+    std::env::var("TERMINAL_COMPANY_THEMES")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_THEME_FILE))
+}
+
+/// True when the `NO_COLOR` convention asks for output without colour.
+pub fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+impl ThemeSet {
+    /// Built-in themes plus the user themes in `path`. A missing file is
+    /// fine; broken themes are skipped and reported as errors.
+    pub fn load(path: &Path) -> Self {
+        let mut set = ThemeSet::default();
+        let Ok(text) = fs::read_to_string(path) else {
+            return set;
+        };
+        match toml::from_str::<ThemeFile>(&text) {
+            Ok(file) => {
+                for spec in &file.themes {
+                    match set.build(spec) {
+                        Ok(theme) => set.insert(theme),
+                        Err(e) => set.errors.push(format!("{}: theme '{}': {}", path.display(), spec.name, e)),
+                    }
+                }
+                if let Some(default) = file.default {
+                    match set.find(&default) {
+                        Some(theme) => set.default = theme.name.clone(),
+                        None => set.errors.push(format!("{}: unknown default theme '{}'", path.display(), default)),
+                    }
+                }
+            }
+            Err(e) => set.errors.push(format!("{}: {}", path.display(), e)),
+        }
+        set
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Theme> {
+        self.themes.iter().find(|t| t.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The theme to start with: monochrome under `NO_COLOR`, otherwise the
+    /// file's default.
+    pub fn startup(&self, no_color: bool) -> Theme {
+        let name = if no_color { NO_COLOR_THEME } else { &self.default };
+        self.find(name).cloned().unwrap_or_else(Theme::classic)
+    }
+
+    /// Adds `theme`, replacing one with the same name.
+    fn insert(&mut self, theme: Theme) {
+        match self.themes.iter_mut().find(|t| t.name.eq_ignore_ascii_case(&theme.name)) {
+            Some(existing) => *existing = theme,
+            None => self.themes.push(theme),
+        }
+    }
+
+    fn build(&self, spec: &ThemeSpec) -> Result<Theme, String> {
+        if spec.name.trim().is_empty() {
+            return Err("a theme needs a name".to_string());
+        }
+        let base_name = spec.base.as_deref().unwrap_or(DEFAULT_THEME);
+        let mut theme = self
+            .find(base_name)
+            .cloned()
+            .ok_or_else(|| format!("unknown base theme '{}'", base_name))?;
+        theme.name = spec.name.trim().to_string();
+
+        let slots = [
+            (&spec.border, &mut theme.border),
+            (&spec.text, &mut theme.text),
+            (&spec.muted, &mut theme.muted),
+            (&spec.accent, &mut theme.accent),
+            (&spec.command, &mut theme.command),
+            (&spec.heading, &mut theme.heading),
+            (&spec.warning, &mut theme.warning),
+            (&spec.error, &mut theme.error),
+            (&spec.success, &mut theme.success),
+        ];
+        for (color, style) in slots {
+            if let Some(color) = color {
+                let color = Color::from_str(color.trim()).map_err(|_| format!("'{}' is not a colour", color))?;
+                *style = style.fg(color);
+            }
+        }
+        Ok(theme)
+    }
+}
@@ -6,6 +6,17 @@ pub fn format_name(name: &str) -> String {
     }
 }
 
+/// What is left of `text` after its first `count` words, with its case
+/// untouched. Used for arguments such as file names that must not be
+/// uppercased along with the command.
+pub fn skip_words(text: &str, count: usize) -> &str {
+    let mut rest = text.trim();
+    for _ in 0..count {
+        rest = rest.split_once(char::is_whitespace).map_or("", |(_, tail)| tail.trim_start());
+    }
+    rest.trim_end()
+}
+
 #[macro_export]  // for global use
 macro_rules! derive_struct {
     (
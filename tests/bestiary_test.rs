@@ -0,0 +1,60 @@
+use terminal_company::models::types::{Bestiary, GameState, Monster};
+
+fn test_monster(name: &str) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Indoor Entity".to_string(),
+        behavior: "Stalking".to_string(),
+        danger_level: Some(50),
+        power_level: 1.0,
+        spawn_condition: "Inside".to_string(),
+        moons: vec!["VOW".to_string()],
+        speed: None,
+        notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
+    }
+}
+
+fn test_bestiary() -> Bestiary {
+    Bestiary {
+        id: None,
+        monsters: vec![
+            test_monster("Bracken"),
+            test_monster("Hoarding Bug"),
+            test_monster("Thumper"),
+        ],
+    }
+}
+
+#[test]
+fn test_find_is_case_insensitive() {
+    let bestiary = test_bestiary();
+
+    assert_eq!(bestiary.find("hoarding bug").unwrap().name, "Hoarding Bug");
+    assert!(bestiary.find("Jester").is_none());
+}
+
+#[test]
+fn test_discovered_keeps_bestiary_order() {
+    let bestiary = test_bestiary();
+    let discovered = vec!["THUMPER".to_string(), "Bracken".to_string()];
+
+    let names: Vec<&str> = bestiary
+        .discovered(&discovered)
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+
+    assert_eq!(names, vec!["Bracken", "Thumper"]);
+}
+
+#[test]
+fn test_discover_creature_only_once() {
+    let mut game_state = GameState::default();
+
+    assert!(game_state.discover_creature("Bracken"));
+    assert!(!game_state.discover_creature("BRACKEN"));
+    assert!(game_state.has_discovered("bracken"));
+    assert_eq!(game_state.discovered_creatures.len(), 1);
+}
@@ -0,0 +1,67 @@
+use terminal_company::ui::bus::{self, EventBus, UiEvent};
+use terminal_company::ui::log::LogKind;
+
+fn logged(events: Vec<UiEvent>) -> Vec<(LogKind, String)> {
+    events
+        .into_iter()
+        .filter_map(|event| match event {
+            UiEvent::Log(entry) => Some((entry.kind, entry.text)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_bus_delivers_events_in_order_and_empties() {
+    let bus = EventBus::new();
+    let publisher = bus.publisher();
+    publisher.info("Scanning...");
+    publisher.success("✨ Done");
+
+    assert_eq!(
+        logged(bus.drain()),
+        vec![(LogKind::Info, "Scanning...".to_string()), (LogKind::Success, "✨ Done".to_string())]
+    );
+    assert!(bus.drain().is_empty());
+}
+
+#[test]
+fn test_publishers_work_from_other_threads() {
+    let bus = EventBus::new();
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let publisher = bus.publisher();
+            std::thread::spawn(move || publisher.info(&format!("worker {}", i)))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(bus.drain().len(), 4);
+}
+
+#[test]
+fn test_macros_publish_to_the_global_bus() {
+    terminal_company::ui_println!("Loading {} moons", 3);
+    terminal_company::ui_eprintln!("mod pack '{}' skipped", "extra");
+
+    let events = logged(bus::drain());
+    assert!(events.contains(&(LogKind::Info, "Loading 3 moons".to_string())));
+    assert!(events.contains(&(LogKind::Warning, "⚠️ mod pack 'extra' skipped".to_string())));
+}
+
+#[tokio::test]
+async fn test_waiting_wakes_up_when_something_is_published() {
+    let bus = std::sync::Arc::new(EventBus::new());
+    let publisher = bus.publisher();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        publisher.info("Autopilot engaged");
+    });
+
+    tokio::time::timeout(std::time::Duration::from_secs(2), bus.wait())
+        .await
+        .expect("the bus never woke up");
+    assert_eq!(bus.drain().len(), 1);
+}
@@ -15,6 +15,10 @@ fn test_calculate_chance_base() {
         threat_level: 0,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -39,6 +43,10 @@ fn test_calculate_chance_with_player_bonus() {
         threat_level: 0,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -69,6 +77,10 @@ fn test_calculate_chance_with_weather_modifier() {
         threat_level: 0,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event_clear = CollectCreditsEvent {
@@ -85,6 +97,10 @@ fn test_calculate_chance_with_weather_modifier() {
         threat_level: 0,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event_rainy = CollectCreditsEvent {
@@ -101,6 +117,10 @@ fn test_calculate_chance_with_weather_modifier() {
         threat_level: 0,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event_stormy = CollectCreditsEvent {
@@ -125,6 +145,10 @@ fn test_calculate_chance_with_threat_level() {
         threat_level: 20,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -150,6 +174,10 @@ fn test_calculate_chance_minimum_is_one() {
         threat_level: 100, // Very high threat
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -178,6 +206,10 @@ fn test_calculate_chance_complex_scenario() {
         threat_level: 40,
         scrap_value: 200,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -203,6 +235,10 @@ fn test_attempt_returns_some_or_none() {
         threat_level: 0,
         scrap_value: 150,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -238,6 +274,10 @@ fn test_attempt_with_zero_chance() {
         threat_level: 0,
         scrap_value: 100,
         monsters: vec![],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
     };
 
     let event = CollectCreditsEvent {
@@ -0,0 +1,77 @@
+use terminal_company::ui::effects::{BootScreen, Reveal, Typewriter, CATCH_UP_TICKS, TYPE_CHARS_PER_TICK};
+use terminal_company::ui::log::{LogEntry, LogKind};
+
+fn info(text: &str) -> LogEntry {
+    LogEntry::new(LogKind::Info, text)
+}
+
+#[test]
+fn test_typewriter_reveals_entries_a_few_characters_at_a_time() {
+    let log = vec![info("Landing on Experimentation"), info("Scan complete")];
+    let mut typewriter = Typewriter::new(true);
+
+    assert_eq!(typewriter.reveal(0), Reveal::Partial(0));
+    assert_eq!(typewriter.reveal(1), Reveal::Hidden);
+
+    typewriter.tick(&log);
+    assert_eq!(typewriter.reveal(0), Reveal::Partial(TYPE_CHARS_PER_TICK));
+
+    for _ in 0..20 {
+        typewriter.tick(&log);
+    }
+    assert!(!typewriter.is_typing(&log));
+    assert_eq!(typewriter.reveal(1), Reveal::Full);
+}
+
+#[test]
+fn test_long_bursts_catch_up_quickly() {
+    let log: Vec<LogEntry> = (0..50).map(|i| info(&format!("HELP line number {} with some text", i))).collect();
+    let mut typewriter = Typewriter::new(true);
+
+    for _ in 0..CATCH_UP_TICKS {
+        typewriter.tick(&log);
+    }
+    assert!(!typewriter.is_typing(&log));
+}
+
+#[test]
+fn test_separators_and_tables_appear_whole() {
+    let log = vec![
+        LogEntry::new(LogKind::Separator, "━━━━━━━━━━━━━━━━━━━━"),
+        LogEntry::table(&["ITEM"], vec![vec!["Shovel".to_string()]]),
+        info("Done"),
+    ];
+    let mut typewriter = Typewriter::new(true);
+    typewriter.tick(&log);
+
+    assert_eq!(typewriter.reveal(0), Reveal::Full);
+    assert_eq!(typewriter.reveal(1), Reveal::Full);
+    assert_eq!(typewriter.reveal(2), Reveal::Full);
+}
+
+#[test]
+fn test_disabled_typewriter_shows_everything() {
+    let log = vec![info("Credits: 60")];
+    let mut typewriter = Typewriter::new(false);
+
+    assert!(!typewriter.is_typing(&log));
+    assert_eq!(typewriter.reveal(0), Reveal::Full);
+
+    typewriter.set_enabled(true, &log);
+    assert_eq!(typewriter.reveal(0), Reveal::Full);
+    assert_eq!(typewriter.reveal(1), Reveal::Partial(0));
+}
+
+#[test]
+fn test_boot_screen_shows_a_line_at_a_time_then_ends() {
+    let mut boot = BootScreen::default();
+    assert_eq!(boot.visible_lines().len(), 1);
+
+    let mut ticks = 0;
+    while !boot.is_done() {
+        boot.tick();
+        ticks += 1;
+        assert!(ticks < 1000);
+    }
+    assert_eq!(boot.visible_lines().len(), boot.lines.len());
+}
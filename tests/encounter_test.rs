@@ -0,0 +1,232 @@
+use rand::{SeedableRng, rngs::StdRng};
+use std::collections::HashMap;
+use terminal_company::models::encounter::{
+    check_engagement, hide_chance, resolve, run_chance, Encounter, EncounterAction, EncounterError,
+    Outcome, REVIVE_HP,
+};
+use terminal_company::models::facility::{Facility, Room};
+use terminal_company::models::items::CHARGE_PER_USE;
+use terminal_company::models::lists::STORE_ITEMS;
+use terminal_company::models::types::{GameState, Item, Monster, Player, Scrap};
+
+fn monster(name: &str, behavior: &str, speed: Option<&str>, danger_level: u32) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Entity".to_string(),
+        behavior: behavior.to_string(),
+        danger_level: Some(danger_level),
+        power_level: 1.0,
+        spawn_condition: "Inside".to_string(),
+        moons: vec!["TITAN".to_string()],
+        speed: speed.map(|s| s.to_string()),
+        notes: None,
+        rarity: None,
+        spawn_weights: HashMap::new(),
+    }
+}
+
+fn store_item(name: &str) -> Item {
+    STORE_ITEMS.iter().find(|i| i.name == name).cloned().unwrap()
+}
+
+fn room(name: &str, exits: Vec<usize>, monsters: Vec<Monster>) -> Room {
+    Room {
+        name: name.to_string(),
+        exits,
+        scrap: vec![],
+        monsters,
+    }
+}
+
+/// Operator standing in the Hallway with `threat` in the room.
+fn cornered(threat: Monster, inventory: Vec<Item>) -> GameState {
+    let mut game_state = GameState::default();
+    game_state.players.push(Player {
+        name: "Tester".to_string(),
+        role: "Operator".to_string(),
+        hp: 100,
+        inventory,
+        credits: 0,
+    });
+    game_state.facility = Some(Facility {
+        moon: "TITAN".to_string(),
+        rooms: vec![
+            room("Main Entrance", vec![1], vec![]),
+            room("Hallway", vec![0, 2], vec![threat.clone()]),
+            room("Vault", vec![1], vec![]),
+        ],
+        current_room: 1,
+        carried: vec![Scrap {
+            name: "Gold Bar".to_string(),
+            value: 120,
+            weight: 20.0,
+        }],
+    });
+    game_state.encounter = Some(Encounter { monster: threat });
+    game_state
+}
+
+#[test]
+fn test_slow_creatures_are_easier_to_outrun() {
+    let slow = monster("Snare Flea", "Ambush", Some("Slow to react"), 30);
+    let fast = monster("Thumper", "Chasing", Some("Fast, persistent"), 60);
+    assert!(run_chance(&slow, 0) > run_chance(&fast, 0));
+}
+
+#[test]
+fn test_heavy_loads_slow_the_operator_down() {
+    let threat = monster("Thumper", "Chasing", None, 60);
+    assert!(run_chance(&threat, 20) < run_chance(&threat, 0));
+    assert!(run_chance(&threat, 200) >= 5);
+}
+
+#[test]
+fn test_hunters_are_harder_to_hide_from() {
+    let hunter = monster("Bracken", "Stalking", None, 70);
+    let guard = monster("Spore Lizard", "Territorial", None, 20);
+    assert!(hide_chance(&hunter) < hide_chance(&guard));
+}
+
+#[test]
+fn test_stun_grenade_always_stuns_and_is_consumed() {
+    let threat = monster("Thumper", "Chasing", Some("Fast, persistent"), 60);
+    let mut game_state = cornered(threat, vec![store_item("Stun Grenade")]);
+
+    let resolution = resolve(
+        &mut game_state,
+        &EncounterAction::Use("stun grenade".to_string()),
+        &mut StdRng::seed_from_u64(1),
+    )
+    .unwrap();
+
+    assert_eq!(resolution.outcome, Outcome::Stunned);
+    assert_eq!(resolution.item_spent.as_deref(), Some("Stun Grenade"));
+    assert!(game_state.encounter.is_none());
+    assert!(game_state.players[0].inventory.is_empty());
+    assert!(game_state.facility.unwrap().current().monsters.is_empty());
+}
+
+#[test]
+fn test_zap_gun_uses_charge_and_fails_when_empty() {
+    let threat = monster("Hoarding Bug", "Territorial", None, 10);
+    let mut zap_gun = store_item("Zap Gun");
+    let mut game_state = cornered(threat.clone(), vec![zap_gun.clone()]);
+
+    resolve(&mut game_state, &EncounterAction::Use("Zap Gun".to_string()), &mut StdRng::seed_from_u64(2)).unwrap();
+    assert_eq!(game_state.players[0].inventory[0].charge, Some(100 - CHARGE_PER_USE));
+
+    zap_gun.charge = Some(0);
+    let mut game_state = cornered(threat, vec![zap_gun]);
+    let resolution = resolve(
+        &mut game_state,
+        &EncounterAction::Use("Zap Gun".to_string()),
+        &mut StdRng::seed_from_u64(2),
+    )
+    .unwrap();
+    assert_eq!(resolution.outcome, Outcome::Hurt);
+    assert_eq!(resolution.item_failed.as_deref(), Some("Zap Gun"));
+    assert!(game_state.encounter.is_some());
+    assert_eq!(game_state.players[0].inventory.len(), 1);
+}
+
+#[test]
+fn test_shovel_wears_out_and_breaks() {
+    let threat = monster("Hoarding Bug", "Territorial", None, 10);
+    let mut shovel = store_item("Shovel");
+    shovel.uses_remaining = Some(1);
+    let mut game_state = cornered(threat, vec![shovel]);
+
+    let resolution = resolve(
+        &mut game_state,
+        &EncounterAction::Use("Shovel".to_string()),
+        &mut StdRng::seed_from_u64(3),
+    )
+    .unwrap();
+
+    assert_eq!(resolution.item_spent.as_deref(), Some("Shovel"));
+    assert!(game_state.players[0].inventory.is_empty());
+}
+
+#[test]
+fn test_tzp_guarantees_an_escape() {
+    let threat = monster("Thumper", "Chasing", Some("Fast, persistent"), 60);
+    let mut game_state = cornered(threat, vec![store_item("TZP-Inhalant")]);
+
+    let resolution = resolve(
+        &mut game_state,
+        &EncounterAction::Use("TZP-Inhalant".to_string()),
+        &mut StdRng::seed_from_u64(4),
+    )
+    .unwrap();
+
+    assert_eq!(resolution.outcome, Outcome::Escaped);
+    let facility = game_state.facility.unwrap();
+    assert_ne!(facility.current_room, 1);
+    assert_eq!(resolution.room.as_deref(), Some(facility.current().name.as_str()));
+    assert!(game_state.players[0].inventory.is_empty());
+}
+
+#[test]
+fn test_items_not_carried_are_rejected() {
+    let threat = monster("Thumper", "Chasing", None, 60);
+    let mut game_state = cornered(threat, vec![]);
+
+    let result = resolve(&mut game_state, &EncounterAction::Use("Shovel".to_string()), &mut StdRng::seed_from_u64(5));
+    assert_eq!(result, Err(EncounterError::NoSuchItem("Shovel".to_string())));
+    assert_eq!(game_state.players[0].hp, 100);
+}
+
+#[test]
+fn test_useless_item_lets_the_creature_strike() {
+    let threat = monster("Thumper", "Chasing", None, 60);
+    let mut game_state = cornered(threat, vec![store_item("Spray Paint")]);
+
+    let resolution = resolve(
+        &mut game_state,
+        &EncounterAction::Use("Spray Paint".to_string()),
+        &mut StdRng::seed_from_u64(6),
+    )
+    .unwrap();
+
+    assert_eq!(resolution.outcome, Outcome::Hurt);
+    assert_eq!(game_state.players[0].hp, 100 - resolution.damage);
+    assert_eq!(game_state.players[0].inventory.len(), 1);
+}
+
+#[test]
+fn test_going_down_loses_carried_scrap() {
+    let threat = monster("Thumper", "Chasing", None, 60);
+    let mut game_state = cornered(threat, vec![store_item("Spray Paint")]);
+    game_state.players[0].hp = 1;
+
+    let resolution = resolve(
+        &mut game_state,
+        &EncounterAction::Use("Spray Paint".to_string()),
+        &mut StdRng::seed_from_u64(7),
+    )
+    .unwrap();
+
+    assert_eq!(resolution.outcome, Outcome::Died);
+    assert!(game_state.facility.is_none());
+    assert!(game_state.encounter.is_none());
+    assert_eq!(game_state.players[0].hp, REVIVE_HP);
+    assert!(game_state.players[0].hp < 100, "going down must not heal the operator");
+}
+
+#[test]
+fn test_no_engagement_without_creatures() {
+    let threat = monster("Thumper", "Chasing", None, 60);
+    let mut game_state = cornered(threat, vec![]);
+    game_state.encounter = None;
+    game_state.facility.as_mut().unwrap().current_room = 2;
+
+    let mut rng = StdRng::seed_from_u64(8);
+    assert!(!(0..50).any(|_| check_engagement(&mut game_state, &mut rng)));
+}
+
+#[test]
+fn test_actions_need_an_encounter() {
+    let mut game_state = GameState::default();
+    let result = resolve(&mut game_state, &EncounterAction::Run, &mut StdRng::seed_from_u64(9));
+    assert_eq!(result, Err(EncounterError::NoEncounter));
+}
@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use terminal_company::models::facility::{Facility, FacilityError, ENTRANCE};
+use terminal_company::models::time_of_day::LANDING_TIME;
+use terminal_company::models::types::{Monster, ScanData};
+
+fn monster(name: &str, spawn_condition: &str) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Entity".to_string(),
+        behavior: "Roaming".to_string(),
+        danger_level: Some(20),
+        power_level: 1.0,
+        spawn_condition: spawn_condition.to_string(),
+        moons: vec!["TITAN".to_string()],
+        speed: None,
+        notes: None,
+        rarity: None,
+        spawn_weights: HashMap::new(),
+    }
+}
+
+fn scan(seed: u64) -> ScanData {
+    ScanData {
+        weather: "Clear".to_string(),
+        threat_level: 10,
+        scrap_value: 640,
+        monsters: vec![
+            monster("Bracken", "Inside (sneak/ambush)"),
+            monster("Masked Mimic", "Anywhere (indoor/outdoor)"),
+            monster("Manticoil", "Outside (daytime)"),
+        ],
+        day: 1,
+        depleted: false,
+        seed,
+        revealed: false,
+    }
+}
+
+fn reachable_rooms(facility: &Facility) -> usize {
+    let mut seen = HashSet::from([0]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(room) = queue.pop_front() {
+        for exit in &facility.rooms[room].exits {
+            if seen.insert(*exit) {
+                queue.push_back(*exit);
+            }
+        }
+    }
+    seen.len()
+}
+
+#[test]
+fn test_same_scan_builds_same_facility() {
+    let first = Facility::generate("TITAN", &scan(5), LANDING_TIME);
+    let second = Facility::generate("TITAN", &scan(5), LANDING_TIME);
+
+    let names = |f: &Facility| f.rooms.iter().map(|r| r.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(&first), names(&second));
+    assert_eq!(first.rooms[1].exits, second.rooms[1].exits);
+}
+
+#[test]
+fn test_every_room_is_reachable() {
+    for seed in 0..50 {
+        let facility = Facility::generate("TITAN", &scan(seed), LANDING_TIME);
+
+        assert_eq!(facility.rooms[0].name, ENTRANCE);
+        assert_eq!(reachable_rooms(&facility), facility.rooms.len());
+    }
+}
+
+#[test]
+fn test_scrap_adds_up_to_scan_value() {
+    for seed in 0..50 {
+        let facility = Facility::generate("VOW", &scan(seed), LANDING_TIME);
+        let total: u32 = facility
+            .rooms
+            .iter()
+            .flat_map(|r| r.scrap.iter())
+            .map(|s| s.value)
+            .sum();
+
+        assert_eq!(total, 640);
+        assert!(facility.rooms[0].scrap.is_empty());
+    }
+}
+
+#[test]
+fn test_only_indoor_capable_monsters_are_placed() {
+    let facility = Facility::generate("TITAN", &scan(9), LANDING_TIME);
+
+    let mut names: Vec<&str> = facility
+        .rooms
+        .iter()
+        .flat_map(|r| r.monsters.iter())
+        .map(|m| m.name.as_str())
+        .collect();
+    names.sort();
+
+    assert_eq!(names, vec!["Bracken", "Masked Mimic"]);
+    assert!(facility.rooms[0].monsters.is_empty());
+}
+
+#[test]
+fn test_move_only_through_exits() {
+    let mut facility = Facility::generate("TITAN", &scan(2), LANDING_TIME);
+    let neighbour = facility.exits()[0].name.clone();
+    let stranger = facility
+        .rooms
+        .iter()
+        .skip(1)
+        .find(|r| !facility.rooms[0].exits.iter().any(|i| facility.rooms[*i].name == r.name))
+        .map(|r| r.name.clone());
+
+    if let Some(stranger) = stranger {
+        assert_eq!(
+            facility.move_to(&stranger).unwrap_err(),
+            FacilityError::NoSuchExit(stranger.clone())
+        );
+    }
+
+    let room = facility.move_to(&neighbour.to_uppercase()).unwrap();
+    assert_eq!(room.name, neighbour);
+    assert!(!facility.at_entrance());
+}
+
+#[test]
+fn test_grab_moves_scrap_into_hands() {
+    let mut facility = Facility::generate("TITAN", &scan(4), LANDING_TIME);
+    let room_index = facility.rooms.iter().position(|r| !r.scrap.is_empty()).unwrap();
+    facility.current_room = room_index;
+    let scrap = facility.rooms[room_index].scrap[0].clone();
+
+    let grabbed = facility.grab(&scrap.name.to_lowercase()).unwrap();
+
+    assert_eq!(grabbed.name, scrap.name);
+    assert_eq!(grabbed.value, scrap.value);
+    assert_eq!(facility.carried_value(), scrap.value);
+    assert_eq!(
+        facility.grab("Golden Toilet").unwrap_err(),
+        FacilityError::NoSuchScrap("Golden Toilet".to_string())
+    );
+}
@@ -0,0 +1,95 @@
+use std::path::Path;
+use terminal_company::models::game_data::{DataFormat, GameData, ValidationIssue};
+
+#[test]
+fn test_builtin_data_is_valid() {
+    let issues = GameData::default().validate();
+    assert!(issues.is_empty(), "Built-in data has issues: {:?}", issues);
+}
+
+#[test]
+fn test_validate_unknown_moon() {
+    let mut game_data = GameData::default();
+    game_data.bestiary[0].moons.push("ATLANTIS".to_string());
+
+    let issues = game_data.validate();
+
+    assert_eq!(
+        issues,
+        vec![ValidationIssue::UnknownMoon {
+            monster: game_data.bestiary[0].name.clone(),
+            moon: "ATLANTIS".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_validate_negative_power_level() {
+    let mut game_data = GameData::default();
+    game_data.bestiary[1].power_level = -2.0;
+
+    let issues = game_data.validate();
+
+    assert_eq!(
+        issues,
+        vec![ValidationIssue::NegativePowerLevel {
+            monster: game_data.bestiary[1].name.clone(),
+            power_level: -2.0,
+        }]
+    );
+}
+
+#[test]
+fn test_validate_duplicate_names_ignore_case() {
+    let mut game_data = GameData::default();
+    game_data.moons.push("vow".to_string());
+    let mut shovel = game_data.store_items[0].clone();
+    shovel.name = shovel.name.to_uppercase();
+    game_data.store_items.push(shovel);
+    let creature = game_data.bestiary[0].clone();
+    game_data.bestiary.push(creature);
+
+    let issues = game_data.validate();
+
+    assert!(issues.contains(&ValidationIssue::DuplicateMoon("vow".to_string())));
+    assert!(issues.contains(&ValidationIssue::DuplicateItem("SHOVEL".to_string())));
+    assert!(issues.contains(&ValidationIssue::DuplicateMonster(game_data.bestiary[0].name.clone())));
+}
+
+#[test]
+fn test_validate_negative_weight() {
+    let mut game_data = GameData::default();
+    game_data.store_items[0].weight = -1.0;
+
+    assert_eq!(
+        game_data.validate(),
+        vec![ValidationIssue::NegativeWeight {
+            item: game_data.store_items[0].name.clone(),
+            weight: -1.0,
+        }]
+    );
+}
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(DataFormat::from_path(Path::new("balance.json")), Some(DataFormat::Json));
+    assert_eq!(DataFormat::from_path(Path::new("balance.TOML")), Some(DataFormat::Toml));
+    assert_eq!(DataFormat::from_path(Path::new("balance.yaml")), None);
+    assert_eq!(DataFormat::from_path(Path::new("balance")), None);
+}
+
+#[test]
+fn test_round_trip_json_and_toml() {
+    let game_data = GameData::default();
+
+    for format in [DataFormat::Json, DataFormat::Toml] {
+        let text = game_data.to_text(format).unwrap();
+        let parsed = GameData::from_text(&text, format).unwrap();
+
+        assert_eq!(parsed.moons, game_data.moons);
+        assert_eq!(parsed.store_items.len(), game_data.store_items.len());
+        assert_eq!(parsed.bestiary.len(), game_data.bestiary.len());
+        assert_eq!(parsed.collect_config.base_chance, game_data.collect_config.base_chance);
+        assert!(parsed.collect_config.id.is_none());
+    }
+}
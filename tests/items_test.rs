@@ -0,0 +1,204 @@
+use terminal_company::models::items::{
+    add_item, capacity, carried_weight, drain_batteries, drop_item, equip, load_weight, player_weight_penalty, recharge,
+    take_item, use_item, weight_penalty, ItemError, BELT_BAG_CAPACITY, CHARGE_PER_USE, FULL_CHARGE, HAND_SLOTS,
+    MAX_WEIGHT_PENALTY,
+};
+use terminal_company::models::lists::STORE_ITEMS;
+use terminal_company::models::types::{Item, Player, Scrap};
+
+fn store_item(name: &str) -> Item {
+    STORE_ITEMS.iter().find(|i| i.name == name).cloned().unwrap()
+}
+
+fn player(items: &[&str]) -> Player {
+    Player {
+        name: "Tester".to_string(),
+        role: "Operator".to_string(),
+        hp: 100,
+        inventory: items.iter().map(|name| store_item(name)).collect(),
+        credits: 0,
+    }
+}
+
+#[test]
+fn test_consumables_leave_inventory_after_use() {
+    let mut player = player(&["Stun Grenade"]);
+
+    let used = use_item(&mut player, "stun grenade").unwrap();
+
+    assert!(used.spent);
+    assert!(player.inventory.is_empty());
+}
+
+#[test]
+fn test_tools_lose_durability() {
+    let mut player = player(&["Weed Killer"]);
+
+    let used = use_item(&mut player, "Weed Killer").unwrap();
+
+    assert!(!used.spent);
+    assert_eq!(used.uses_remaining, Some(9));
+    assert_eq!(player.inventory[0].uses_remaining, Some(9));
+}
+
+#[test]
+fn test_battery_items_cannot_be_used_flat() {
+    let mut player = player(&["Zap Gun"]);
+    player.inventory[0].charge = Some(CHARGE_PER_USE - 1);
+
+    let result = use_item(&mut player, "Zap Gun");
+
+    assert_eq!(result, Err(ItemError::BatteryDead("Zap Gun".to_string())));
+    assert_eq!(player.inventory[0].charge, Some(CHARGE_PER_USE - 1));
+}
+
+#[test]
+fn test_equip_swaps_items_in_the_same_slot() {
+    let mut player = player(&["Flashlight", "Shovel", "Belt Bag"]);
+
+    assert_eq!(equip(&mut player, "Flashlight"), Ok(None));
+    assert_eq!(equip(&mut player, "Belt Bag"), Ok(None));
+    assert_eq!(equip(&mut player, "Shovel"), Ok(Some("Flashlight".to_string())));
+
+    let equipped: Vec<&str> = player.inventory.iter().filter(|i| i.equipped).map(|i| i.name.as_str()).collect();
+    assert_eq!(equipped, vec!["Shovel", "Belt Bag"]);
+}
+
+#[test]
+fn test_equip_rejects_unequippable_items() {
+    let mut player = player(&["Survival Kit"]);
+    assert_eq!(
+        equip(&mut player, "Survival Kit"),
+        Err(ItemError::NotEquippable("Survival Kit".to_string()))
+    );
+}
+
+#[test]
+fn test_only_equipped_batteries_drain() {
+    let mut player = player(&["Flashlight", "Pro-flashlight"]);
+    equip(&mut player, "Flashlight").unwrap();
+
+    drain_batteries(&mut player);
+
+    let flashlight = &player.inventory[0];
+    assert_eq!(flashlight.charge, Some(FULL_CHARGE - flashlight.battery_drain));
+    assert_eq!(player.inventory[1].charge, Some(FULL_CHARGE));
+}
+
+#[test]
+fn test_drain_reports_batteries_going_flat_once() {
+    let mut player = player(&["Flashlight"]);
+    equip(&mut player, "Flashlight").unwrap();
+    player.inventory[0].charge = Some(1);
+
+    assert_eq!(drain_batteries(&mut player), vec!["Flashlight".to_string()]);
+    assert!(drain_batteries(&mut player).is_empty());
+    assert_eq!(player.inventory[0].charge, Some(0));
+}
+
+#[test]
+fn test_recharge_and_drop() {
+    let mut player = player(&["Zap Gun", "Shovel"]);
+    player.inventory[0].charge = Some(10);
+
+    assert_eq!(recharge(&mut player, "zap gun"), Ok(10));
+    assert_eq!(player.inventory[0].charge, Some(FULL_CHARGE));
+    assert_eq!(recharge(&mut player, "Shovel"), Err(ItemError::NoBattery("Shovel".to_string())));
+
+    let dropped = drop_item(&mut player, "Shovel").unwrap();
+    assert_eq!(dropped.name, "Shovel");
+    assert_eq!(player.inventory.len(), 1);
+}
+
+#[test]
+fn test_hands_hold_four_items() {
+    let mut player = player(&["Flashlight", "Flashlight", "Shovel", "Stun Grenade"]);
+
+    let result = add_item(&mut player, store_item("Walkie-Talkie"));
+
+    assert_eq!(result, Err(ItemError::InventoryFull("Walkie-Talkie".to_string())));
+    assert_eq!(player.inventory.len(), HAND_SLOTS);
+}
+
+#[test]
+fn test_belt_bag_adds_capacity_without_taking_a_slot() {
+    let mut player = player(&["Flashlight", "Flashlight", "Shovel", "Stun Grenade"]);
+
+    add_item(&mut player, store_item("Belt Bag")).unwrap();
+    assert_eq!(capacity(&player), HAND_SLOTS + BELT_BAG_CAPACITY);
+    add_item(&mut player, store_item("Walkie-Talkie")).unwrap();
+
+    assert_eq!(drop_item(&mut player, "Belt Bag").err(), Some(ItemError::BagNotEmpty));
+    drop_item(&mut player, "Walkie-Talkie").unwrap();
+    assert!(drop_item(&mut player, "Belt Bag").is_ok());
+}
+
+#[test]
+fn test_belt_bag_holds_four_items() {
+    let mut player = player(&["Flashlight", "Flashlight", "Shovel", "Stun Grenade", "Belt Bag"]);
+    for _ in 0..4 {
+        add_item(&mut player, store_item("Walkie-Talkie")).unwrap();
+    }
+
+    let result = add_item(&mut player, store_item("Walkie-Talkie"));
+
+    assert_eq!(result, Err(ItemError::InventoryFull("Walkie-Talkie".to_string())));
+    assert!(store_item("Belt Bag").description.contains(&format!("Holds {} items", BELT_BAG_CAPACITY)));
+}
+
+#[test]
+fn test_spare_belt_bag_can_be_dropped_but_not_the_last_one() {
+    let mut player = player(&["Flashlight", "Flashlight", "Shovel", "Stun Grenade", "Belt Bag", "Belt Bag"]);
+    add_item(&mut player, store_item("Walkie-Talkie")).unwrap();
+
+    assert!(drop_item(&mut player, "Belt Bag").is_ok());
+    assert_eq!(drop_item(&mut player, "Belt Bag").err(), Some(ItemError::BagNotEmpty));
+    assert_eq!(capacity(&player), HAND_SLOTS + BELT_BAG_CAPACITY);
+}
+
+#[test]
+fn test_taking_from_storage_needs_a_free_slot() {
+    let mut player = player(&["Flashlight", "Flashlight", "Shovel"]);
+    let mut storage = vec![store_item("Walkie-Talkie"), store_item("Zap Gun")];
+
+    assert_eq!(take_item(&mut player, &mut storage, "zap gun"), Ok("Zap Gun".to_string()));
+    assert_eq!(
+        take_item(&mut player, &mut storage, "Walkie-Talkie"),
+        Err(ItemError::InventoryFull("Walkie-Talkie".to_string()))
+    );
+    assert_eq!(storage.len(), 1);
+    assert_eq!(take_item(&mut player, &mut storage, "Shovel"), Err(ItemError::NotInStorage("Shovel".to_string())));
+}
+
+#[test]
+fn test_weight_penalty_grows_past_the_free_allowance() {
+    let player = player(&["Shovel", "Zap Gun", "Flashlight"]);
+
+    assert_eq!(weight_penalty(0.0), 0);
+    assert_eq!(weight_penalty(20.0), 0);
+    assert_eq!(weight_penalty(30.0), 5);
+    assert_eq!(weight_penalty(1000.0), MAX_WEIGHT_PENALTY);
+    let expected: f32 = player.inventory.iter().map(|i| i.weight).sum();
+    assert_eq!(carried_weight(&player), expected);
+}
+
+#[test]
+fn test_carried_scrap_counts_towards_the_penalty() {
+    let player = player(&["Flashlight"]);
+    let scrap = vec![
+        Scrap {
+            name: "Large Axle".to_string(),
+            value: 40,
+            weight: 40.0,
+        },
+        Scrap {
+            name: "Brass Bell".to_string(),
+            value: 50,
+            weight: 20.0,
+        },
+    ];
+
+    assert_eq!(load_weight(&player, &scrap), carried_weight(&player) + 60.0);
+    assert_eq!(player_weight_penalty(&player, &[]), weight_penalty(carried_weight(&player)));
+    assert!(player_weight_penalty(&player, &scrap) > player_weight_penalty(&player, &[]));
+}
@@ -0,0 +1,78 @@
+use ratatui::layout::Rect;
+use terminal_company::ui::app::{Panel, Panels};
+use terminal_company::ui::clipboard::osc52;
+use terminal_company::ui::components::{
+    body_layout, log_window, screen_layout, tab_at, MIN_WIDTH_FOR_PANELS, SIDE_PANEL_WIDTH,
+};
+
+#[test]
+fn test_wide_terminals_show_every_panel_beside_the_log() {
+    let area = Rect::new(0, 3, 140, 40);
+    let (log, panels) = body_layout(area, &Panels::default());
+
+    assert_eq!(panels.len(), 3);
+    assert_eq!(log.width, 140 - SIDE_PANEL_WIDTH);
+    assert!(panels.iter().all(|(_, rect)| rect.x == log.width && rect.width == SIDE_PANEL_WIDTH));
+    let stacked: u16 = panels.iter().map(|(_, rect)| rect.height).sum();
+    assert_eq!(stacked, 40);
+}
+
+#[test]
+fn test_narrow_terminals_collapse_the_panels() {
+    let area = Rect::new(0, 0, MIN_WIDTH_FOR_PANELS - 1, 40);
+    let (log, panels) = body_layout(area, &Panels::default());
+
+    assert!(panels.is_empty());
+    assert_eq!(log, area);
+}
+
+#[test]
+fn test_toggled_panels_give_their_space_back() {
+    let mut toggles = Panels::default();
+    toggles.toggle(Panel::Scan);
+    let area = Rect::new(0, 0, 120, 30);
+
+    let (_, panels) = body_layout(area, &toggles);
+    let shown: Vec<Panel> = panels.iter().map(|(panel, _)| *panel).collect();
+    assert_eq!(shown, vec![Panel::Status, Panel::Inventory]);
+
+    toggles.toggle(Panel::Status);
+    toggles.toggle(Panel::Inventory);
+    let (log, panels) = body_layout(area, &toggles);
+    assert!(panels.is_empty());
+    assert_eq!(log, area);
+}
+
+#[test]
+fn test_panel_tabs_sit_in_the_footer_and_map_clicks_to_panels() {
+    let area = Rect::new(0, 0, 120, 40);
+    let layout = screen_layout(area, &Panels::default());
+    let tabs = layout.tabs.expect("wide terminals show tabs");
+
+    assert_eq!(tabs.y, 39);
+    assert_eq!(layout.footer.x, tabs.right());
+    assert_eq!(tab_at(tabs, tabs.x), Some(Panel::Status));
+    let crew = Panel::Status.title().chars().count() as u16;
+    assert_eq!(tab_at(tabs, tabs.x + crew), Some(Panel::Scan));
+    assert_eq!(tab_at(tabs, tabs.right()), None);
+
+    let narrow = screen_layout(Rect::new(0, 0, MIN_WIDTH_FOR_PANELS - 1, 40), &Panels::default());
+    assert!(narrow.tabs.is_none());
+}
+
+#[test]
+fn test_log_window_shows_the_newest_lines_unless_scrolled() {
+    let area = Rect::new(0, 0, 80, 12);
+
+    assert_eq!(log_window(100, area, 0), 90..100);
+    assert_eq!(log_window(100, area, 5), 85..95);
+    assert_eq!(log_window(100, area, 500), 0..10);
+    assert_eq!(log_window(4, area, 0), 0..4);
+}
+
+#[test]
+fn test_copied_text_is_base64_encoded_for_osc52() {
+    assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+    assert_eq!(osc52("Zap Gun"), "\x1b]52;c;WmFwIEd1bg==\x07");
+    assert_eq!(osc52("Shovel"), "\x1b]52;c;U2hvdmVs\x07");
+}
@@ -0,0 +1,82 @@
+use terminal_company::models::ledger::{daily_summary, LedgerFilter, TransactionKind};
+use terminal_company::models::types::{GameState, Player};
+
+fn game_state() -> GameState {
+    let mut game_state = GameState::default();
+    game_state.players.push(Player {
+        name: "Tester".to_string(),
+        role: "Operator".to_string(),
+        hp: 100,
+        inventory: vec![],
+        credits: 100,
+    });
+    game_state
+}
+
+/// Applies a credit change the way the commands do and records it.
+fn transact(game_state: &mut GameState, kind: TransactionKind, amount: i64) {
+    let credits = &mut game_state.players[0].credits;
+    *credits = (*credits as i64 + amount) as u32;
+    game_state.record_transaction(kind, amount, "test");
+}
+
+#[test]
+fn test_entries_record_the_balance_after_each_change() {
+    let mut game_state = game_state();
+    transact(&mut game_state, TransactionKind::Purchase, -30);
+    game_state.ship.location = "TITAN".to_string();
+    transact(&mut game_state, TransactionKind::Collect, 250);
+
+    let ledger = &game_state.ledger;
+    assert_eq!(ledger.len(), 2);
+    assert_eq!(ledger[0].balance, 70);
+    assert_eq!(ledger[1].balance, 320);
+    assert_eq!(ledger[1].location, "TITAN");
+    assert_eq!(ledger[1].day, 1);
+}
+
+#[test]
+fn test_filters_combine() {
+    let mut game_state = game_state();
+    transact(&mut game_state, TransactionKind::Purchase, -30);
+    transact(&mut game_state, TransactionKind::Rescan, -15);
+    game_state.day = 2;
+    transact(&mut game_state, TransactionKind::ScrapSale, 90);
+    transact(&mut game_state, TransactionKind::Purchase, -12);
+
+    let ledger = &game_state.ledger;
+    let filter = LedgerFilter::parse("purchase").unwrap();
+    assert_eq!(filter.apply(ledger).len(), 2);
+
+    let filter = LedgerFilter::parse("DAY 2 SPENDING").unwrap();
+    let entries = filter.apply(ledger);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].amount, -12);
+
+    assert_eq!(LedgerFilter::parse("INCOME").unwrap().apply(ledger).len(), 1);
+    assert!(LedgerFilter::parse("DAY x").is_err());
+    assert!(LedgerFilter::parse("BRIBES").is_err());
+}
+
+#[test]
+fn test_daily_summary_splits_income_and_spending() {
+    let mut game_state = game_state();
+    transact(&mut game_state, TransactionKind::Purchase, -30);
+    transact(&mut game_state, TransactionKind::Collect, 200);
+    game_state.day = 3;
+    transact(&mut game_state, TransactionKind::Rescan, -15);
+
+    let entries = LedgerFilter::default().apply(&game_state.ledger);
+    let summary = daily_summary(&entries);
+
+    assert_eq!(summary.len(), 2);
+    assert_eq!((summary[0].day, summary[0].income, summary[0].spending), (1, 200, 30));
+    assert_eq!(summary[0].net(), 170);
+    assert_eq!(summary[1].net(), -15);
+}
+
+#[test]
+fn test_kinds_pad_in_tables() {
+    assert_eq!(format!("{:<9}|", TransactionKind::Refund), "REFUND   |");
+    assert_eq!(TransactionKind::parse("scrap"), Some(TransactionKind::ScrapSale));
+}
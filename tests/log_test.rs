@@ -0,0 +1,79 @@
+use std::time::{Duration, UNIX_EPOCH};
+use terminal_company::ui::log::{wrap, LogEntry, LogKind};
+use unicode_width::UnicodeWidthStr;
+
+fn row(cells: &[&str]) -> Vec<String> {
+    cells.iter().map(|c| c.to_string()).collect()
+}
+
+#[test]
+fn test_log_kinds_parse_from_labels_and_plurals() {
+    assert_eq!(LogKind::parse("errors"), Some(LogKind::Error));
+    assert_eq!(LogKind::parse("WARNING"), Some(LogKind::Warning));
+    assert_eq!(LogKind::parse("success"), Some(LogKind::Success));
+    assert_eq!(LogKind::parse("commands"), Some(LogKind::Command));
+    assert_eq!(LogKind::parse("everything"), None);
+}
+
+#[test]
+fn test_tables_line_up_their_columns() {
+    let entry = LogEntry::table(&["SLOT", "ITEM"], vec![row(&["1", "Shovel"]), row(&["Belt", "Belt Bag"])]);
+
+    assert_eq!(entry.lines(80), vec!["SLOT  ITEM", "1     Shovel", "Belt  Belt Bag"]);
+}
+
+#[test]
+fn test_narrow_tables_cut_the_widest_column() {
+    let entry = LogEntry::table(
+        &["ITEM", "NOTE"],
+        vec![row(&["Zap Gun", "Bought on sale at the Company building"])],
+    );
+
+    let lines = entry.lines(24);
+    assert!(lines.iter().all(|line| line.chars().count() <= 24));
+    assert_eq!(lines[1], "Zap Gun  Bought on sale…");
+}
+
+#[test]
+fn test_export_prefixes_every_line_with_time_and_kind() {
+    let mut entry = LogEntry::table(&["DAY", "KIND"], vec![row(&["1", "SCRAP"])]);
+    entry.timestamp = UNIX_EPOCH + Duration::from_secs(3600 * 13 + 60 * 5 + 9);
+
+    assert_eq!(
+        entry.export(),
+        "[13:05:09] TABLE     DAY  KIND\n[13:05:09] TABLE     1    SCRAP"
+    );
+
+    let mut warning = LogEntry::new(LogKind::Warning, "⚠️ Battery low");
+    warning.timestamp = entry.timestamp;
+    assert_eq!(warning.export(), "[13:05:09] WARNING   ⚠️ Battery low");
+}
+
+#[test]
+fn test_long_lines_wrap_at_spaces_to_the_pane_width() {
+    let text = "Scan complete: 3 signatures detected near the main entrance";
+
+    let lines = wrap(text, 20);
+    assert!(lines.iter().all(|line| line.chars().count() <= 20));
+    assert_eq!(lines.join(" "), text);
+    assert_eq!(lines[0], "Scan complete: 3");
+
+    assert_eq!(wrap("━━━━━━━━━━", 4), vec!["━━━━", "━━━━", "━━"]);
+    assert_eq!(wrap("short", 20), vec!["short"]);
+    assert_eq!(LogEntry::new(LogKind::Info, text).lines(40).len(), 2);
+}
+
+#[test]
+fn test_wide_characters_count_as_two_columns() {
+    let text = "✨ 🛒 Added 3 x Flashlight to your cart 📍 Company";
+
+    let lines = wrap(text, 12);
+    assert!(lines.iter().all(|line| line.width() <= 12), "{:?}", lines);
+    assert_eq!(lines.join(" "), text);
+    assert_eq!(wrap("🛒🛒🛒", 4), vec!["🛒🛒", "🛒"]);
+
+    let entry = LogEntry::table(&["ITEM", "NOTE"], vec![row(&["🛒 Shovel", "✨✨✨✨✨✨"])]);
+    let lines = entry.lines(20);
+    assert!(lines.iter().all(|line| line.width() <= 20), "{:?}", lines);
+    assert_eq!(lines[1], "🛒 Shovel  ✨✨✨✨…");
+}
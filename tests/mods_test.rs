@@ -0,0 +1,133 @@
+use std::fs;
+use terminal_company::data::mods::{apply_packs, load_packs, ContentKind, ContentPack};
+use terminal_company::models::game_data::GameData;
+use terminal_company::models::types::{Item, Monster};
+
+fn pack(name: &str) -> ContentPack {
+    ContentPack {
+        name: name.to_string(),
+        version: None,
+        description: None,
+        moons: vec![],
+        store_items: vec![],
+        bestiary: vec![],
+    }
+}
+
+fn item(name: &str, price: u32) -> Item {
+    Item {
+        name: name.to_string(),
+        price,
+        weight: 1.0,
+        description: "Modded".to_string(),
+        uses_remaining: None,
+        charge: None,
+        battery_drain: 0,
+        consumable: false,
+        slot: None,
+        equipped: false,
+    }
+}
+
+fn monster(name: &str, moon: &str) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Indoor Entity".to_string(),
+        behavior: "Roaming".to_string(),
+        danger_level: Some(30),
+        power_level: 1.0,
+        spawn_condition: "Inside".to_string(),
+        moons: vec![moon.to_string()],
+        speed: None,
+        notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
+    }
+}
+
+#[test]
+fn test_apply_packs_adds_new_content() {
+    let mut game_data = GameData::default();
+    let mut moon_pack = pack("Gordion Expansion");
+    moon_pack.moons = vec!["gordion".to_string()];
+    moon_pack.bestiary = vec![monster("Old Bird", "GORDION")];
+
+    let report = apply_packs(&mut game_data, &[moon_pack]);
+
+    assert!(report.conflicts.is_empty());
+    assert!(game_data.is_known_moon("GORDION"));
+    assert!(game_data.bestiary.iter().any(|m| m.name == "Old Bird"));
+    assert!(game_data.validate().is_empty());
+    assert_eq!(report.packs[0].moons, 1);
+}
+
+#[test]
+fn test_later_pack_overrides_and_reports_conflict() {
+    let mut game_data = GameData::default();
+    let mut first = pack("cheap-shovels");
+    first.store_items = vec![item("shovel", 5)];
+    let mut second = pack("pricey-shovels");
+    second.store_items = vec![item("Shovel", 500)];
+
+    let report = apply_packs(&mut game_data, &[first, second]);
+
+    let shovels: Vec<&Item> = game_data
+        .store_items
+        .iter()
+        .filter(|i| i.name.eq_ignore_ascii_case("shovel"))
+        .collect();
+    assert_eq!(shovels.len(), 1);
+    assert_eq!(shovels[0].price, 500);
+
+    assert_eq!(report.conflicts.len(), 2);
+    assert_eq!(report.conflicts[0].kind, ContentKind::Item);
+    assert_eq!(report.conflicts[0].previous_source, "built-in");
+    assert_eq!(report.conflicts[0].overridden_by, "cheap-shovels");
+    assert_eq!(report.conflicts[1].previous_source, "cheap-shovels");
+    assert_eq!(report.conflicts[1].overridden_by, "pricey-shovels");
+}
+
+#[test]
+fn test_duplicate_moon_is_reported_not_added() {
+    let mut game_data = GameData::default();
+    let moons_before = game_data.moons.len();
+    let mut moon_pack = pack("vow-again");
+    moon_pack.moons = vec!["Vow".to_string()];
+
+    let report = apply_packs(&mut game_data, &[moon_pack]);
+
+    assert_eq!(game_data.moons.len(), moons_before);
+    assert_eq!(report.conflicts[0].kind, ContentKind::Moon);
+}
+
+#[test]
+fn test_load_packs_from_directory() {
+    let dir = std::env::temp_dir().join(format!("tc_mods_test_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("b_items.json"),
+        r#"{ "name": "Item Pack", "store_items": [{ "name": "Jetpack", "price": 700, "weight": 52.0, "description": "Fly." }] }"#,
+    )
+    .unwrap();
+    fs::write(dir.join("a_moons.toml"), "version = \"1.0\"\nmoons = [\"EMBRION\", \"GORDION\"]\n").unwrap();
+    fs::write(dir.join("broken.json"), "{ not json").unwrap();
+    fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+    let (packs, errors) = load_packs(&dir);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(packs.len(), 2);
+    assert_eq!(packs[0].name, "a_moons");
+    assert_eq!(packs[0].version.as_deref(), Some("1.0"));
+    assert_eq!(packs[1].name, "Item Pack");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("broken.json"));
+}
+
+#[test]
+fn test_missing_directory_means_no_mods() {
+    let (packs, errors) = load_packs(std::path::Path::new("/definitely/not/a/mods/dir"));
+
+    assert!(packs.is_empty());
+    assert!(errors.is_empty());
+}
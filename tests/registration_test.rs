@@ -0,0 +1,15 @@
+use terminal_company::commands::registration::new_game_state;
+
+#[test]
+fn test_new_game_starts_docked_at_the_company() {
+    let game_state = new_game_state("Ripley", "Scavenger");
+
+    assert_eq!(game_state.id.as_deref(), Some("game_state"));
+    assert_eq!(game_state.players.len(), 1);
+    assert_eq!(game_state.players[0].name, "Ripley");
+    assert_eq!(game_state.players[0].role, "Scavenger");
+    assert_eq!(game_state.players[0].credits, 30);
+    assert_eq!(game_state.ship.location, "Company");
+    assert_eq!(game_state.day, 1);
+    assert!(game_state.scan_data.is_empty());
+}
@@ -1,7 +1,7 @@
 use terminal_company::models::scan_logic::{
-    calculate_threat_level, danger_value, generate_scan_data, moon_tier,
+    calculate_threat_level, danger_value, generate_scan_data, is_scan_expired, moon_tier,
 };
-use terminal_company::models::types::{Bestiary, Monster};
+use terminal_company::models::types::{Bestiary, GameState, Monster, ScanData};
 
 #[test]
 fn test_moon_tier_level_1() {
@@ -61,7 +61,7 @@ fn test_danger_value_with_none() {
 #[test]
 fn test_calculate_threat_level_empty() {
     let monsters: Vec<&Monster> = vec![];
-    assert_eq!(calculate_threat_level(&monsters), 0);
+    assert_eq!(calculate_threat_level(&monsters, "Clear"), 0);
 }
 
 #[test]
@@ -76,12 +76,15 @@ fn test_calculate_threat_level_single_monster() {
         moons: vec!["VOW".to_string()],
         speed: Some("Fast".to_string()),
         notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
     };
 
     let monsters = vec![&monster];
-    // danger_value(20) = 20/20 = 1.0
-    // 1.0 * 2.0 = 2.0 -> ceil = 2
-    assert_eq!(calculate_threat_level(&monsters), 2);
+    // base: danger_value(20) * 2.0 = 2.0
+    // aggressive (x1.3), placement anywhere (x1.0) -> 2.6
+    // 100 * (1 - e^(-2.6/12)) = 19.5 -> 19
+    assert_eq!(calculate_threat_level(&monsters, "Clear"), 19);
 }
 
 #[test]
@@ -96,6 +99,8 @@ fn test_calculate_threat_level_multiple_monsters() {
         moons: vec!["VOW".to_string()],
         speed: Some("Fast".to_string()),
         notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
     };
 
     let monster2 = Monster {
@@ -108,13 +113,15 @@ fn test_calculate_threat_level_multiple_monsters() {
         moons: vec!["VOW".to_string()],
         speed: Some("Slow".to_string()),
         notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
     };
 
     let monsters = vec![&monster1, &monster2];
-    // monster1: (20/20) * 2.0 = 2.0 -> 2
-    // monster2: (10/20) * 3.0 = 1.5 -> 2
-    // Total: 2 + 2 = 4
-    assert_eq!(calculate_threat_level(&monsters), 4);
+    // monster1: 1.0 * 2.0 * 1.3 (aggressive) = 2.6
+    // monster2: 0.5 * 3.0 * 0.3 (passive) = 0.45
+    // (2.6 + 0.45) * 1.1 (two monsters stack) = 3.355 -> 24
+    assert_eq!(calculate_threat_level(&monsters, "Clear"), 24);
 }
 
 #[test]
@@ -129,12 +136,14 @@ fn test_calculate_threat_level_with_none_danger() {
         moons: vec!["TITAN".to_string()],
         speed: None,
         notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
     };
 
     let monsters = vec![&monster];
     // danger_value(None) = 1.0
-    // 1.0 * 5.0 = 5.0 -> 5
-    assert_eq!(calculate_threat_level(&monsters), 5);
+    // 1.0 * 5.0 = 5.0 -> 100 * (1 - e^(-5/12)) = 34
+    assert_eq!(calculate_threat_level(&monsters, "Clear"), 34);
 }
 
 #[test]
@@ -149,12 +158,14 @@ fn test_calculate_threat_level_minimum_is_one() {
         moons: vec!["EXPERIMENTATION".to_string()],
         speed: Some("Very Slow".to_string()),
         notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
     };
 
     let monsters = vec![&monster];
     // danger_value(1) = 1/20 = 0.05
     // 0.05 * 0.01 = 0.0005 -> ceil = 1 -> but then clamped to 1
-    let threat = calculate_threat_level(&monsters);
+    let threat = calculate_threat_level(&monsters, "Clear");
     assert!(threat >= 1, "Threat level should be at least 1, got {}", threat);
 }
 
@@ -173,6 +184,8 @@ fn test_generate_scan_data_basic() {
                 moons: vec!["VOW".to_string(), "EXPERIMENTATION".to_string()],
                 speed: Some("Fast".to_string()),
                 notes: None,
+                rarity: None,
+                spawn_weights: Default::default(),
             },
         ],
     };
@@ -200,6 +213,8 @@ fn test_generate_scan_data_no_monsters_for_moon() {
                 moons: vec!["TITAN".to_string()], // Only on TITAN
                 speed: Some("Fast".to_string()),
                 notes: None,
+                rarity: None,
+                spawn_weights: Default::default(),
             },
         ],
     };
@@ -226,6 +241,8 @@ fn test_generate_scan_data_tier_affects_monster_count() {
             moons: vec!["EXPERIMENTATION".to_string(), "LIQUIDATION".to_string()],
             speed: Some("Medium".to_string()),
             notes: None,
+            rarity: None,
+            spawn_weights: Default::default(),
         });
     }
 
@@ -237,7 +254,7 @@ fn test_generate_scan_data_tier_affects_monster_count() {
     // Tier 1 moon: should select 1-3 monsters
     let scan_tier1 = generate_scan_data("EXPERIMENTATION", "Clear", 100, &bestiary);
     assert!(
-        scan_tier1.monsters.len() >= 1 && scan_tier1.monsters.len() <= 3,
+        !scan_tier1.monsters.is_empty() && scan_tier1.monsters.len() <= 3,
         "Tier 1 should have 1-3 monsters, got {}",
         scan_tier1.monsters.len()
     );
@@ -250,3 +267,70 @@ fn test_generate_scan_data_tier_affects_monster_count() {
         scan_tier4.monsters.len()
     );
 }
+
+fn scan_for_day(day: u32) -> ScanData {
+    ScanData {
+        weather: "Clear".to_string(),
+        threat_level: 0,
+        scrap_value: 100,
+        monsters: vec![],
+        day,
+        depleted: false,
+        seed: 0,
+        revealed: false,
+    }
+}
+
+#[test]
+fn test_scan_expires_when_day_advances() {
+    let scan = scan_for_day(2);
+
+    assert!(is_scan_expired(&scan, 3));
+    assert!(!is_scan_expired(&scan, 2));
+}
+
+#[test]
+fn test_advance_day_drops_expired_scans() {
+    let mut game_state = GameState::default();
+    game_state.scan_data.insert("VOW".to_string(), scan_for_day(1));
+    game_state.scan_data.insert("TITAN".to_string(), scan_for_day(2));
+
+    game_state.advance_day();
+
+    assert_eq!(game_state.day, 2);
+    assert!(!game_state.scan_data.contains_key("VOW"));
+    assert!(game_state.scan_data.contains_key("TITAN"));
+}
+
+#[test]
+fn test_generated_scan_starts_fresh() {
+    let bestiary = Bestiary {
+        id: None,
+        monsters: vec![],
+    };
+
+    let scan = generate_scan_data("VOW", "Clear", 100, &bestiary);
+
+    assert!(!scan.depleted);
+}
+
+#[test]
+fn test_rescan_keeps_a_collected_moon_depleted_for_the_day() {
+    let mut game_state = GameState::default();
+    game_state.store_scan("VOW", scan_for_day(1));
+
+    // COLLECT picks the moon clean and reveals its contents
+    let scan = game_state.scan_data.get_mut("VOW").unwrap();
+    scan.depleted = true;
+    scan.revealed = true;
+
+    // RESCAN on the same day must not make it collectable again
+    game_state.store_scan("VOW", scan_for_day(1));
+    let scan = &game_state.scan_data["VOW"];
+    assert!(scan.depleted, "a second COLLECT on the same day should be refused");
+    assert!(!scan.revealed, "freshly rolled readings haven't been surveyed");
+
+    game_state.advance_day();
+    game_state.store_scan("VOW", scan_for_day(2));
+    assert!(!game_state.scan_data["VOW"].depleted);
+}
@@ -0,0 +1,122 @@
+use terminal_company::models::scan_report::{build_report, scan_confidence, Sighting};
+use terminal_company::models::types::{Monster, Player, ScanData, Ship};
+
+fn test_monster(name: &str) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Indoor Entity".to_string(),
+        behavior: "Aggressive".to_string(),
+        danger_level: Some(40),
+        power_level: 2.0,
+        spawn_condition: "Inside".to_string(),
+        moons: vec!["VOW".to_string()],
+        speed: None,
+        notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
+    }
+}
+
+fn test_scan(monsters: Vec<Monster>) -> ScanData {
+    ScanData {
+        weather: "Foggy".to_string(),
+        threat_level: 40,
+        scrap_value: 500,
+        monsters,
+        day: 1,
+        depleted: false,
+        seed: 42,
+        revealed: false,
+    }
+}
+
+fn test_player(role: &str) -> Player {
+    Player {
+        name: "Tester".to_string(),
+        role: role.to_string(),
+        hp: 100,
+        inventory: vec![],
+        credits: 0,
+    }
+}
+
+fn test_ship(upgrades: Vec<&str>) -> Ship {
+    Ship {
+        location: "VOW".to_string(),
+        number_operators_alive: 1,
+        upgrades: upgrades.into_iter().map(String::from).collect(),
+        decorations: vec![],
+        scrap: vec![],
+        storage: vec![],
+    }
+}
+
+#[test]
+fn test_scan_confidence_base() {
+    assert_eq!(scan_confidence(&test_player("Employee"), &test_ship(vec![])), 40);
+}
+
+#[test]
+fn test_scan_confidence_scout_and_upgrades() {
+    let ship = test_ship(vec!["Signal Translator", "Teleporter"]);
+    // 40 base + 20 scout + 20 translator + 5 teleporter
+    assert_eq!(scan_confidence(&test_player("scout"), &ship), 85);
+}
+
+#[test]
+fn test_scan_confidence_is_capped() {
+    let ship = test_ship(vec!["Signal Translator", "Signal Translator", "Signal Translator"]);
+    assert_eq!(scan_confidence(&test_player("Scout"), &ship), 95);
+}
+
+#[test]
+fn test_report_ranges_contain_true_values() {
+    for seed in 0..200 {
+        let mut scan = test_scan(vec![]);
+        scan.seed = seed;
+        let report = build_report(&scan, 40, &[]);
+
+        assert!(report.threat_range.0 <= 40 && 40 <= report.threat_range.1);
+        assert!(report.scrap_range.0 <= 500 && 500 <= report.scrap_range.1);
+        assert_eq!(report.scrap_range.1 - report.scrap_range.0, 600);
+    }
+}
+
+#[test]
+fn test_report_is_stable_for_same_scan() {
+    let scan = test_scan(vec![test_monster("Bracken"), test_monster("Thumper")]);
+
+    assert_eq!(build_report(&scan, 50, &[]), build_report(&scan, 50, &[]));
+}
+
+#[test]
+fn test_known_creatures_are_always_identified() {
+    let scan = test_scan(vec![test_monster("Bracken"), test_monster("Thumper")]);
+    let known = vec!["BRACKEN".to_string(), "thumper".to_string()];
+
+    let report = build_report(&scan, 0, &known);
+
+    assert_eq!(report.identified_names(), vec!["Bracken", "Thumper"]);
+}
+
+#[test]
+fn test_zero_confidence_hides_unknown_creatures() {
+    let scan = test_scan(vec![test_monster("Bracken")]);
+
+    let report = build_report(&scan, 0, &[]);
+
+    assert_eq!(report.sightings, vec![Sighting::UnknownSignature]);
+}
+
+#[test]
+fn test_revealed_scan_reports_exact_values() {
+    let mut scan = test_scan(vec![test_monster("Bracken")]);
+    scan.revealed = true;
+
+    let report = build_report(&scan, 10, &[]);
+
+    assert_eq!(report.confidence, 100);
+    assert_eq!(report.threat_range, (40, 40));
+    assert_eq!(report.scrap_range, (500, 500));
+    assert_eq!(report.identified_names(), vec!["Bracken"]);
+}
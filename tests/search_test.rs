@@ -0,0 +1,37 @@
+use terminal_company::ui::search::{centre_on, find_matches, match_ranges, next_match, SearchDirection};
+
+#[test]
+fn test_matches_ignore_case() {
+    assert_eq!(match_ranges("Zap Gun bought. ZAP!", "zap"), vec![0..3, 16..19]);
+    assert!(match_ranges("Shovel", "").is_empty());
+
+    let lines = ["> STORE", "- Zap gun:", "- Shovel:", "✨ Bought a zap gun"];
+    assert_eq!(find_matches(lines, "ZAP GUN"), vec![1, 3]);
+}
+
+#[test]
+fn test_matches_inside_emoji_lines_keep_valid_byte_ranges() {
+    let line = "✨ You have purchased 'Flashlight'";
+    for range in match_ranges(line, "flashlight") {
+        assert_eq!(&line[range], "Flashlight");
+    }
+}
+
+#[test]
+fn test_n_and_shift_n_step_through_matches_and_wrap() {
+    let matches = [2, 5, 9];
+
+    assert_eq!(next_match(&matches, None, SearchDirection::Older), Some(9));
+    assert_eq!(next_match(&matches, Some(9), SearchDirection::Older), Some(5));
+    assert_eq!(next_match(&matches, Some(2), SearchDirection::Older), Some(9));
+    assert_eq!(next_match(&matches, Some(5), SearchDirection::Newer), Some(9));
+    assert_eq!(next_match(&matches, Some(9), SearchDirection::Newer), Some(2));
+    assert_eq!(next_match(&[], None, SearchDirection::Older), None);
+}
+
+#[test]
+fn test_matches_are_scrolled_to_the_middle_of_the_pane() {
+    assert_eq!(centre_on(50, 100, 20), 39);
+    assert_eq!(centre_on(99, 100, 20), 0);
+    assert_eq!(centre_on(0, 5, 20), 0);
+}
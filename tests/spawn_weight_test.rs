@@ -0,0 +1,187 @@
+use rand::{SeedableRng, rngs::StdRng};
+use std::collections::HashMap;
+use terminal_company::models::lists::BESTIARY;
+use terminal_company::models::scan_logic::{
+    generate_scan_data_with_rng, max_power, spawn_weight, DEFAULT_SPAWN_WEIGHT,
+};
+use terminal_company::models::types::{Bestiary, Monster};
+
+fn monster(name: &str, power_level: f32, rarity: Option<u32>) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Indoor Entity".to_string(),
+        behavior: "Roaming".to_string(),
+        danger_level: Some(20),
+        power_level,
+        spawn_condition: "Inside".to_string(),
+        moons: vec!["VOW".to_string(), "TITAN".to_string()],
+        speed: None,
+        notes: None,
+        rarity,
+        spawn_weights: HashMap::new(),
+    }
+}
+
+fn count_by_name(bestiary: &Bestiary, moon: &str, samples: u64) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..samples {
+        let scan = generate_scan_data_with_rng(moon, "Clear", 100, bestiary, &mut rng);
+        for monster in scan.monsters {
+            *counts.entry(monster.name).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+#[test]
+fn test_spawn_weight_lookup_order() {
+    let mut creature = monster("Ghost Girl", 2.0, Some(5));
+    creature.spawn_weights.insert("DINE".to_string(), 25);
+
+    assert_eq!(spawn_weight(&creature, "dine"), 25);
+    assert_eq!(spawn_weight(&creature, "TITAN"), 5);
+    assert_eq!(spawn_weight(&monster("Plain", 1.0, None), "VOW"), DEFAULT_SPAWN_WEIGHT);
+}
+
+#[test]
+fn test_max_power_grows_with_tier() {
+    assert!(max_power("EXPERIMENTATION") < max_power("OFFENSE"));
+    assert!(max_power("OFFENSE") < max_power("TITAN"));
+    assert!(max_power("TITAN") < max_power("ARTIFICE"));
+}
+
+#[test]
+fn test_distribution_follows_rarity() {
+    let bestiary = Bestiary {
+        id: None,
+        monsters: vec![
+            monster("Hoarding Bug", 1.0, Some(90)),
+            monster("Ghost Girl", 1.0, Some(10)),
+        ],
+    };
+
+    let counts = count_by_name(&bestiary, "VOW", 2000);
+    let common = counts["Hoarding Bug"] as f32;
+    let rare = counts["Ghost Girl"] as f32;
+    let share = rare / (common + rare);
+
+    assert!((0.07..0.13).contains(&share), "Expected ~10% rare spawns, got {:.3}", share);
+}
+
+#[test]
+fn test_zero_weight_never_spawns() {
+    let bestiary = Bestiary {
+        id: None,
+        monsters: vec![monster("Common", 1.0, Some(50)), monster("Disabled", 1.0, Some(0))],
+    };
+
+    let counts = count_by_name(&bestiary, "TITAN", 500);
+
+    assert!(!counts.contains_key("Disabled"));
+}
+
+#[test]
+fn test_repeats_are_allowed() {
+    let bestiary = Bestiary {
+        id: None,
+        monsters: vec![monster("Only Bug", 1.0, None)],
+    };
+    let mut rng = StdRng::seed_from_u64(3);
+
+    let scan = generate_scan_data_with_rng("TITAN", "Clear", 100, &bestiary, &mut rng);
+
+    assert!(scan.monsters.len() >= 3, "Tier 3 should pick 3-5 instances");
+    assert!(scan.monsters.iter().all(|m| m.name == "Only Bug"));
+}
+
+#[test]
+fn test_power_budget_is_respected() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let bestiary = Bestiary {
+        id: None,
+        monsters: BESTIARY.to_vec(),
+    };
+
+    for moon in ["EXPERIMENTATION", "OFFENSE", "DINE", "ARTIFICE"] {
+        for _ in 0..300 {
+            let scan = generate_scan_data_with_rng(moon, "Clear", 100, &bestiary, &mut rng);
+            let power: f32 = scan.monsters.iter().map(|m| m.power_level).sum();
+            assert!(power <= max_power(moon), "{} exceeded its budget: {}", moon, power);
+        }
+    }
+}
+
+#[test]
+fn test_monster_too_strong_for_budget_is_skipped() {
+    let bestiary = Bestiary {
+        id: None,
+        monsters: vec![monster("Giant", 100.0, None)],
+    };
+    let mut rng = StdRng::seed_from_u64(5);
+
+    let scan = generate_scan_data_with_rng("VOW", "Clear", 100, &bestiary, &mut rng);
+
+    assert!(scan.monsters.is_empty());
+}
+
+#[test]
+fn test_same_seed_same_scan() {
+    let bestiary = Bestiary {
+        id: None,
+        monsters: BESTIARY.to_vec(),
+    };
+
+    let first = generate_scan_data_with_rng("DINE", "Foggy", 300, &bestiary, &mut StdRng::seed_from_u64(99));
+    let second = generate_scan_data_with_rng("DINE", "Foggy", 300, &bestiary, &mut StdRng::seed_from_u64(99));
+
+    let names = |scan: &terminal_company::models::types::ScanData| {
+        scan.monsters.iter().map(|m| m.name.clone()).collect::<Vec<_>>()
+    };
+    assert_eq!(names(&first), names(&second));
+    assert_eq!(first.seed, second.seed);
+}
+
+#[test]
+fn test_bestiary_saved_without_weights_gets_compiled_defaults() {
+    use mongodb::bson::{doc, from_document};
+
+    // A bestiary seeded before creatures had spawn weights
+    let document = doc! {
+        "_id": "bestiary",
+        "monsters": [
+            {
+                "name": "Hoarding Bug",
+                "entity_type": "Indoor Entity",
+                "behavior": "Territorial",
+                "danger_level": 10,
+                "power_level": 1.0,
+                "spawn_condition": "Inside",
+                "moons": ["VOW"],
+                "speed": null,
+                "notes": null,
+            },
+            {
+                "name": "Modded Crawler",
+                "entity_type": "Indoor Entity",
+                "behavior": "Roaming",
+                "danger_level": 20,
+                "power_level": 2.0,
+                "spawn_condition": "Inside",
+                "moons": ["VOW"],
+                "speed": null,
+                "notes": null,
+            },
+        ],
+    };
+    let mut bestiary: Bestiary = from_document(document).unwrap();
+    assert_eq!(bestiary.monsters[0].rarity, None);
+
+    assert!(bestiary.fill_spawn_defaults());
+
+    let compiled = BESTIARY.iter().find(|m| m.name == "Hoarding Bug").unwrap();
+    assert_eq!(bestiary.monsters[0].rarity, compiled.rarity);
+    assert_eq!(bestiary.monsters[0].spawn_weights, compiled.spawn_weights);
+    assert_eq!(spawn_weight(&bestiary.monsters[1], "VOW"), DEFAULT_SPAWN_WEIGHT);
+    assert!(!bestiary.fill_spawn_defaults(), "filling in twice changes nothing");
+}
@@ -0,0 +1,258 @@
+use terminal_company::models::items::{BELT_BAG_CAPACITY, HAND_SLOTS};
+use terminal_company::models::lists::STORE_ITEMS;
+use terminal_company::models::store::{
+    add_to_cart, buy_rate, cart_total, checkout, daily_sales, parse_order, quota_markup, sell_item, sell_scrap,
+    CartLine, DailyPrices, StoreError, MAX_QUANTITY, MIN_BUY_RATE,
+};
+use terminal_company::models::types::{GameState, Item, Player, QUOTA_DAYS, Quota, Scrap, StoreConfig};
+
+fn store_item(name: &str) -> Item {
+    STORE_ITEMS.iter().find(|i| i.name == name).cloned().unwrap()
+}
+
+fn player(credits: u32) -> Player {
+    Player {
+        name: "Tester".to_string(),
+        role: "Operator".to_string(),
+        hp: 100,
+        inventory: vec![],
+        credits,
+    }
+}
+
+fn line(name: &str, quantity: u32) -> CartLine {
+    CartLine {
+        item: store_item(name),
+        quantity,
+    }
+}
+
+#[test]
+fn test_parse_order_reads_an_optional_quantity() {
+    assert_eq!(parse_order("flashlight"), Ok((1, "flashlight")));
+    assert_eq!(parse_order("3 walkie-talkie"), Ok((3, "walkie-talkie")));
+    assert_eq!(parse_order("zap gun"), Ok((1, "zap gun")));
+    assert_eq!(parse_order("0 shovel"), Err(StoreError::InvalidQuantity("0".to_string())));
+    assert_eq!(parse_order("500 shovel"), Err(StoreError::InvalidQuantity("500".to_string())));
+}
+
+#[test]
+fn test_cart_merges_lines_for_the_same_item() {
+    let mut cart = vec![];
+    add_to_cart(&mut cart, store_item("Flashlight"), 2);
+    add_to_cart(&mut cart, store_item("Walkie-Talkie"), 1);
+    add_to_cart(&mut cart, store_item("Flashlight"), 1);
+
+    assert_eq!(cart.len(), 2);
+    assert_eq!(cart[0].quantity, 3);
+    assert_eq!(cart_total(&cart), 3 * 15 + 12);
+}
+
+#[test]
+fn test_cart_lines_are_capped_at_the_maximum_quantity() {
+    let mut cart = vec![];
+
+    assert_eq!(add_to_cart(&mut cart, store_item("Flashlight"), MAX_QUANTITY - 1), MAX_QUANTITY - 1);
+    assert_eq!(add_to_cart(&mut cart, store_item("Flashlight"), 5), MAX_QUANTITY);
+    assert_eq!(add_to_cart(&mut cart, store_item("Shovel"), MAX_QUANTITY + 1), MAX_QUANTITY);
+    assert_eq!(cart[0].quantity, MAX_QUANTITY);
+}
+
+#[test]
+fn test_checkout_buys_everything() {
+    let mut player = player(100);
+    let order = vec![line("Flashlight", 2), line("Walkie-Talkie", 2)];
+
+    assert_eq!(checkout(&mut player, &mut vec![], &order), Ok(54));
+    assert_eq!(player.credits, 46);
+    assert_eq!(player.inventory.len(), 4);
+}
+
+#[test]
+fn test_checkout_buys_nothing_when_short_of_credits() {
+    let mut player = player(50);
+    let order = vec![line("Flashlight", 2), line("Zap Gun", 1)];
+
+    assert_eq!(
+        checkout(&mut player, &mut vec![], &order),
+        Err(StoreError::NotEnoughCredits { needed: 430, available: 50 })
+    );
+    assert_eq!(player.credits, 50);
+    assert!(player.inventory.is_empty());
+}
+
+#[test]
+fn test_bulk_orders_overflow_into_ship_storage() {
+    let mut player = player(1000);
+    let mut storage = vec![];
+    let order = vec![line("Flashlight", HAND_SLOTS as u32 + 6)];
+
+    assert_eq!(checkout(&mut player, &mut storage, &order), Ok(15 * (HAND_SLOTS as u32 + 6)));
+    assert_eq!(player.inventory.len(), HAND_SLOTS);
+    assert_eq!(storage.len(), 6);
+    assert!(storage.iter().all(|item| item.name == "Flashlight"));
+}
+
+#[test]
+fn test_belt_bag_in_the_same_order_makes_room() {
+    let mut player = player(1000);
+    let walkies = (HAND_SLOTS + BELT_BAG_CAPACITY) as u32;
+    let order = vec![line("Walkie-Talkie", walkies), line("Belt Bag", 1)];
+
+    let mut storage = vec![];
+
+    assert!(checkout(&mut player, &mut storage, &order).is_ok());
+    assert_eq!(player.inventory.len(), walkies as usize + 1);
+    assert!(storage.is_empty());
+}
+
+#[test]
+fn test_empty_cart_cannot_be_checked_out() {
+    assert_eq!(checkout(&mut player(10), &mut vec![], &[]), Err(StoreError::EmptyCart));
+}
+
+#[test]
+fn test_daily_sales_are_deterministic_per_seed_and_day() {
+    let config = StoreConfig::default();
+
+    let first = daily_sales(42, 3, &STORE_ITEMS, &config);
+    assert_eq!(first, daily_sales(42, 3, &STORE_ITEMS, &config));
+
+    let days: Vec<_> = (1..=10).map(|day| daily_sales(42, day, &STORE_ITEMS, &config)).collect();
+    assert!(days.iter().any(|sales| *sales != days[0]));
+}
+
+#[test]
+fn test_daily_sales_respect_the_config() {
+    let config = StoreConfig::default();
+    for day in 1..=30 {
+        let sales = daily_sales(7, day, &STORE_ITEMS, &config);
+        assert!(sales.len() <= config.max_daily_sales as usize);
+        for discount in sales.values() {
+            assert!((config.min_discount..=config.max_discount).contains(discount));
+            assert_eq!(discount % 10, 0);
+        }
+    }
+}
+
+#[test]
+fn test_prices_rise_with_quota_progress() {
+    let config = StoreConfig::default();
+    let mut quota = Quota::default();
+    assert_eq!(quota_markup(&quota, &config), 0);
+
+    quota.fulfilled = quota.target / 2;
+    assert_eq!(quota_markup(&quota, &config), config.quota_markup / 2);
+
+    quota.fulfilled = quota.target * 3;
+    assert_eq!(quota_markup(&quota, &config), config.quota_markup);
+}
+
+#[test]
+fn test_sale_price_is_taken_off_the_list_price() {
+    let zap_gun = store_item("Zap Gun");
+    let prices = DailyPrices {
+        sales: [("Zap Gun".to_string(), 50)].into_iter().collect(),
+        markup: 10,
+    };
+
+    assert_eq!(prices.list_price(&zap_gun), 440);
+    assert_eq!(prices.price(&zap_gun), 220);
+    assert_eq!(prices.priced(&zap_gun).price, 220);
+    assert_eq!(prices.price(&store_item("Shovel")), 33);
+}
+
+fn scrap(name: &str, value: u32) -> Scrap {
+    Scrap {
+        name: name.to_string(),
+        value,
+        weight: 10.0,
+    }
+}
+
+#[test]
+fn test_buy_rate_peaks_on_the_deadline() {
+    let quota = Quota::default();
+    assert_eq!(buy_rate(&quota, quota.deadline_day), 100);
+    assert_eq!(buy_rate(&quota, quota.deadline_day + 2), 100);
+    assert!(buy_rate(&quota, quota.deadline_day - 1) < 100);
+    assert_eq!(buy_rate(&quota, 0), MIN_BUY_RATE.max(100 - 4 * 20));
+}
+
+#[test]
+fn test_quota_rolls_over_after_the_deadline() {
+    let config = StoreConfig::default();
+    let mut game_state = GameState::default();
+    let deadline = game_state.quota.deadline_day;
+    let target = game_state.quota.target;
+    game_state.quota.fulfilled = target;
+    game_state.day = deadline;
+
+    game_state.advance_day();
+    assert_eq!(game_state.quota.fulfilled, 0);
+    assert!(game_state.quota.target > target);
+    assert_eq!(game_state.quota.deadline_day, deadline + QUOTA_DAYS);
+    assert_eq!(quota_markup(&game_state.quota, &config), 0);
+    assert!(buy_rate(&game_state.quota, game_state.day) < 100);
+}
+
+#[test]
+fn test_quota_holds_until_the_deadline_has_passed() {
+    let mut quota = Quota {
+        fulfilled: 50,
+        ..Quota::default()
+    };
+    assert!(!quota.roll_over(quota.deadline_day));
+    assert_eq!(quota.fulfilled, 50);
+
+    let deadline = quota.deadline_day;
+    assert!(quota.roll_over(deadline + QUOTA_DAYS + 1));
+    assert_eq!(quota.deadline_day, deadline + 2 * QUOTA_DAYS);
+}
+
+#[test]
+fn test_selling_an_item_refunds_part_of_its_price() {
+    let mut player = player(0);
+    player.inventory.push(store_item("Shovel"));
+    let config = StoreConfig::default();
+
+    let receipt = sell_item(&mut player, "shovel", &config).unwrap();
+
+    assert_eq!(receipt.total, 30 * config.refund_percent / 100);
+    assert_eq!(player.credits, receipt.total);
+    assert!(player.inventory.is_empty());
+    assert_eq!(sell_item(&mut player, "Shovel", &config), Err(StoreError::NothingToSell("Shovel".to_string())));
+}
+
+#[test]
+fn test_a_full_belt_bag_cannot_be_sold() {
+    let mut player = player(0);
+    for name in ["Flashlight", "Flashlight", "Shovel", "Stun Grenade", "Belt Bag", "Walkie-Talkie"] {
+        player.inventory.push(store_item(name));
+    }
+    let config = StoreConfig::default();
+
+    assert_eq!(sell_item(&mut player, "BELT BAG", &config), Err(StoreError::BagNotEmpty("Belt Bag".to_string())));
+    assert_eq!(player.credits, 0);
+    assert_eq!(player.inventory.len(), 6);
+}
+
+#[test]
+fn test_selling_scrap_pays_the_buy_rate_and_fills_the_quota() {
+    let mut game_state = GameState::default();
+    game_state.players.push(player(10));
+    game_state.day = game_state.quota.deadline_day;
+    game_state.ship.scrap = vec![scrap("Gold Bar", 120), scrap("Brass Bell", 40), scrap("Gold Bar", 90)];
+
+    let receipt = sell_scrap(&mut game_state, Some("gold bar")).unwrap();
+    assert_eq!(receipt.total, 120);
+    assert_eq!(game_state.ship.scrap.len(), 2);
+
+    let receipt = sell_scrap(&mut game_state, None).unwrap();
+    assert_eq!(receipt.lines.len(), 2);
+    assert_eq!(receipt.total, 130);
+    assert_eq!(receipt.balance, 260);
+    assert_eq!(game_state.quota.fulfilled, 250);
+    assert!(game_state.ship.scrap.is_empty());
+    assert_eq!(sell_scrap(&mut game_state, None), Err(StoreError::NothingToSell("scrap".to_string())));
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+use terminal_company::ui::bus::{EventBus, UiEvent};
+use terminal_company::ui::tasks::{spinner, TaskResult, Tasks};
+
+async fn wait_for_events(bus: &EventBus) -> Vec<UiEvent> {
+    for _ in 0..100 {
+        let events = bus.drain();
+        if !events.is_empty() {
+            return events;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    vec![]
+}
+
+#[tokio::test]
+async fn test_finished_tasks_report_back_over_the_bus() {
+    let bus = EventBus::new();
+    let mut tasks = Tasks::default();
+    let id = tasks.spawn("Saving game", bus.publisher(), async { TaskResult::Saved(Ok(())) });
+    assert!(tasks.is_busy());
+
+    let events = wait_for_events(&bus).await;
+    let Some(UiEvent::TaskDone(done)) = events.into_iter().next() else {
+        panic!("the task never reported back");
+    };
+    assert_eq!(done.id, id);
+    assert!(matches!(done.result, TaskResult::Saved(Ok(()))));
+    assert!(tasks.finish(id));
+    assert!(!tasks.is_busy());
+}
+
+#[tokio::test]
+async fn test_cancelled_tasks_never_report_back() {
+    let bus = EventBus::new();
+    let mut tasks = Tasks::default();
+    let id = tasks.spawn("Loading game", bus.publisher(), async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        TaskResult::Loaded(Ok(None))
+    });
+
+    assert_eq!(tasks.cancel(), Some("Loading game".to_string()));
+    assert!(!tasks.is_busy());
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(bus.drain().is_empty());
+    assert!(!tasks.finish(id));
+}
+
+#[test]
+fn test_spinner_advances_every_tenth_of_a_second() {
+    assert_ne!(spinner(Duration::from_millis(0)), spinner(Duration::from_millis(100)));
+    assert_eq!(spinner(Duration::from_millis(0)), spinner(Duration::from_millis(1000)));
+}
@@ -0,0 +1,77 @@
+use ratatui::style::Color;
+use std::fs;
+use terminal_company::ui::log::LogKind;
+use terminal_company::ui::theme::{Theme, ThemeSet, NO_COLOR_THEME};
+
+fn theme_file(name: &str, text: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("terminal-company-{}-{}.toml", name, std::process::id()));
+    fs::write(&path, text).unwrap();
+    path
+}
+
+#[test]
+fn test_built_in_themes_can_be_found_by_any_case() {
+    let themes = ThemeSet::default();
+    for name in ["classic", "AMBER", "Light", "high-contrast", "mono"] {
+        assert!(themes.find(name).is_some(), "missing theme {}", name);
+    }
+    assert_eq!(themes.startup(false).name, "classic");
+}
+
+#[test]
+fn test_no_color_starts_in_monochrome() {
+    let theme = ThemeSet::default().startup(true);
+    assert_eq!(theme.name, NO_COLOR_THEME);
+    for kind in LogKind::ALL {
+        let style = theme.log_style(kind);
+        assert_eq!((style.fg, style.bg), (None, None));
+    }
+}
+
+#[test]
+fn test_user_themes_override_their_base() {
+    let path = theme_file(
+        "user",
+        r##"
+default = "paper"
+
+[[themes]]
+name = "paper"
+base = "light"
+border = "#268bd2"
+error = "magenta"
+"##,
+    );
+    let themes = ThemeSet::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert!(themes.errors.is_empty(), "{:?}", themes.errors);
+    let paper = themes.startup(false);
+    assert_eq!(paper.name, "paper");
+    assert_eq!(paper.border.fg, Some(Color::Rgb(0x26, 0x8b, 0xd2)));
+    assert_eq!(paper.error.fg, Some(Color::Magenta));
+    assert_eq!(paper.text, Theme::light().text);
+}
+
+#[test]
+fn test_broken_user_themes_are_reported_and_skipped() {
+    let path = theme_file(
+        "broken",
+        r##"
+[[themes]]
+name = "neon"
+border = "not-a-colour"
+
+[[themes]]
+name = "ghost"
+base = "missing"
+"##,
+    );
+    let themes = ThemeSet::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(themes.errors.len(), 2);
+    assert!(themes.find("neon").is_none());
+    assert!(themes.find("ghost").is_none());
+    assert_eq!(themes.themes.len(), Theme::built_in().len());
+}
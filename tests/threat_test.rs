@@ -0,0 +1,102 @@
+use terminal_company::models::threat::{
+    assess, behavior_factor, normalise, placement_factor, stacking_factor, weather_factor,
+};
+use terminal_company::models::time_of_day::Placement;
+use terminal_company::models::types::Monster;
+
+fn monster(behavior: &str, spawn_condition: &str, danger_level: u32, power_level: f32) -> Monster {
+    Monster {
+        name: format!("{} {}", behavior, spawn_condition),
+        entity_type: "Entity".to_string(),
+        behavior: behavior.to_string(),
+        danger_level: Some(danger_level),
+        power_level,
+        spawn_condition: spawn_condition.to_string(),
+        moons: vec!["VOW".to_string()],
+        speed: None,
+        notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
+    }
+}
+
+#[test]
+fn test_behavior_factor_keywords() {
+    assert_eq!(behavior_factor("Roaming (docile)"), 0.3);
+    assert_eq!(behavior_factor("Looting / Territorial"), 1.0);
+    assert_eq!(behavior_factor("Territorial / Defensive"), 0.8);
+    assert_eq!(behavior_factor("Pack hunting / Sound-tracking"), 1.3);
+    assert_eq!(behavior_factor("Something new"), 1.0);
+}
+
+#[test]
+fn test_most_dangerous_behavior_wins() {
+    assert_eq!(behavior_factor("Defensive / Aggressive"), 1.3);
+}
+
+#[test]
+fn test_placement_and_weather_factors() {
+    assert!(placement_factor(Placement::Indoor) > placement_factor(Placement::Anywhere));
+    assert!(placement_factor(Placement::Outdoor) < placement_factor(Placement::Anywhere));
+    assert_eq!(weather_factor("Clear"), 1.0);
+    assert!(weather_factor("Eclipsed") > weather_factor("Stormy"));
+    assert_eq!(weather_factor("Unknown"), 1.0);
+}
+
+#[test]
+fn test_stacking_factor() {
+    assert_eq!(stacking_factor(0), 1.0);
+    assert_eq!(stacking_factor(1), 1.0);
+    assert!((stacking_factor(4) - 1.3).abs() < 1e-6);
+}
+
+#[test]
+fn test_normalise_range() {
+    assert_eq!(normalise(0.0), 0);
+    assert_eq!(normalise(0.0001), 1);
+    assert_eq!(normalise(10_000.0), 100);
+    assert!(normalise(5.0) < normalise(10.0));
+}
+
+#[test]
+fn test_assess_stays_within_scale() {
+    let brutes: Vec<Monster> = (0..20)
+        .map(|_| monster("Aggressive / Ambush", "Inside", 100, 3.0))
+        .collect();
+    let refs: Vec<&Monster> = brutes.iter().collect();
+
+    let breakdown = assess(&refs, "Eclipsed");
+
+    assert_eq!(breakdown.level, 100);
+    assert_eq!(breakdown.contributions.len(), 20);
+}
+
+#[test]
+fn test_assess_breakdown_values() {
+    let hunter = monster("Hunting", "Inside", 40, 1.0);
+    let grazer = monster("Passive", "Outside", 40, 1.0);
+
+    let breakdown = assess(&[&hunter, &grazer], "Foggy");
+
+    // hunter: 2.0 * 1.3 * 1.2 = 3.12, grazer: 2.0 * 0.3 * 0.8 = 0.48
+    assert!((breakdown.contributions[0].score - 3.12).abs() < 1e-4);
+    assert!((breakdown.contributions[1].score - 0.48).abs() < 1e-4);
+    assert!((breakdown.stacking_factor - 1.1).abs() < 1e-6);
+    assert!((breakdown.weather_factor - 1.2).abs() < 1e-6);
+    assert!((breakdown.raw_total - 3.6 * 1.1 * 1.2).abs() < 1e-4);
+}
+
+#[test]
+fn test_hunters_are_worse_than_passive_creatures() {
+    let hunter = monster("Hunting", "Inside", 50, 2.0);
+    let grazer = monster("Passive", "Inside", 50, 2.0);
+
+    assert!(assess(&[&hunter], "Clear").level > assess(&[&grazer], "Clear").level);
+}
+
+#[test]
+fn test_weather_raises_threat() {
+    let hunter = monster("Hunting", "Inside", 50, 2.0);
+
+    assert!(assess(&[&hunter], "Stormy").level > assess(&[&hunter], "Clear").level);
+}
@@ -0,0 +1,136 @@
+use terminal_company::models::time_of_day::{
+    format_clock, land, pass_time, scan_at_time, ActivePeriod, Placement, SpawnCondition,
+    LANDING_TIME, MIDNIGHT, NIGHTFALL,
+};
+use terminal_company::models::types::{GameState, Monster, ScanData};
+
+fn monster_with_condition(name: &str, condition: &str) -> Monster {
+    Monster {
+        name: name.to_string(),
+        entity_type: "Entity".to_string(),
+        behavior: "Aggressive".to_string(),
+        danger_level: Some(20),
+        power_level: 2.0,
+        spawn_condition: condition.to_string(),
+        moons: vec!["VOW".to_string()],
+        speed: None,
+        notes: None,
+        rarity: None,
+        spawn_weights: Default::default(),
+    }
+}
+
+#[test]
+fn test_parse_outdoor_daytime() {
+    let condition = SpawnCondition::parse("Outside (daytime, hives on ground)");
+
+    assert_eq!(condition.placement, Placement::Outdoor);
+    assert_eq!(condition.period, ActivePeriod::Day);
+    assert!(condition.weather.is_empty());
+}
+
+#[test]
+fn test_parse_indoor_always() {
+    let condition = SpawnCondition::parse("Inside (sneak/ambush)");
+
+    assert_eq!(condition.placement, Placement::Indoor);
+    assert_eq!(condition.period, ActivePeriod::Always);
+
+    assert_eq!(SpawnCondition::parse("Rare indoor spawn").placement, Placement::Indoor);
+}
+
+#[test]
+fn test_parse_anywhere() {
+    let condition = SpawnCondition::parse("Anywhere (indoor/outdoor, mimics objects/players)");
+
+    assert_eq!(condition.placement, Placement::Anywhere);
+}
+
+#[test]
+fn test_parse_night_with_eclipse() {
+    let condition = SpawnCondition::parse("Outside (active during darkness/eclipses)");
+
+    assert_eq!(condition.placement, Placement::Outdoor);
+    assert_eq!(condition.period, ActivePeriod::Night);
+    assert_eq!(condition.weather, vec!["Eclipsed".to_string()]);
+}
+
+#[test]
+fn test_is_active_by_time_and_weather() {
+    let day = SpawnCondition::parse("Outside (daytime)");
+    let night = SpawnCondition::parse("Outside (night/eclipses)");
+
+    assert!(day.is_active(LANDING_TIME, "Clear"));
+    assert!(!day.is_active(NIGHTFALL, "Clear"));
+    assert!(!day.is_active(LANDING_TIME, "Eclipsed"));
+
+    assert!(!night.is_active(LANDING_TIME, "Clear"));
+    assert!(night.is_active(NIGHTFALL, "Clear"));
+    assert!(night.is_active(LANDING_TIME, "Eclipsed"));
+}
+
+#[test]
+fn test_format_clock() {
+    assert_eq!(format_clock(LANDING_TIME), "8:00 AM");
+    assert_eq!(format_clock(12 * 60), "12:00 PM");
+    assert_eq!(format_clock(NIGHTFALL + 30), "6:30 PM");
+    assert_eq!(format_clock(MIDNIGHT - 1), "11:59 PM");
+}
+
+#[test]
+fn test_scan_at_time_filters_monsters() {
+    let scan = ScanData {
+        weather: "Clear".to_string(),
+        threat_level: 6,
+        scrap_value: 100,
+        monsters: vec![
+            monster_with_condition("Manticoil", "Outside (daytime)"),
+            monster_with_condition("Blind Hound", "Outside (night/eclipses)"),
+            monster_with_condition("Bracken", "Inside (sneak/ambush)"),
+        ],
+        day: 1,
+        depleted: false,
+        seed: 0,
+        revealed: false,
+    };
+
+    let morning = scan_at_time(&scan, LANDING_TIME);
+    let names: Vec<&str> = morning.monsters.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["Manticoil", "Bracken"]);
+    assert_eq!(morning.threat_level, 38);
+
+    let evening = scan_at_time(&scan, NIGHTFALL);
+    let names: Vec<&str> = evening.monsters.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["Blind Hound", "Bracken"]);
+}
+
+#[test]
+fn test_pass_time_advances_clock() {
+    let mut game_state = GameState::default();
+    game_state.ship.location = "VOW".to_string();
+    land(&mut game_state);
+
+    assert!(!pass_time(&mut game_state, 60));
+    assert_eq!(game_state.landed_time, Some(LANDING_TIME + 60));
+    assert_eq!(game_state.ship.location, "VOW");
+}
+
+#[test]
+fn test_pass_time_forces_departure_at_midnight() {
+    let mut game_state = GameState::default();
+    game_state.ship.location = "VOW".to_string();
+    land(&mut game_state);
+
+    assert!(pass_time(&mut game_state, MIDNIGHT));
+    assert_eq!(game_state.landed_time, None);
+    assert_eq!(game_state.ship.location, "COMPANY");
+    assert_eq!(game_state.day, 2);
+}
+
+#[test]
+fn test_pass_time_does_nothing_in_orbit() {
+    let mut game_state = GameState::default();
+
+    assert!(!pass_time(&mut game_state, MIDNIGHT));
+    assert_eq!(game_state.day, 1);
+}
@@ -1,4 +1,4 @@
-use terminal_company::utils::shortcut::{format_name};
+use terminal_company::utils::shortcut::{format_name, skip_words};
 
 #[test]
 fn test_format_name_lowercase() {
@@ -73,3 +73,11 @@ fn test_format_name_repeated_chars() {
     assert_eq!(format_name("aaaa"), "Aaaa");
     assert_eq!(format_name("ZZZZ"), "Zzzz");
 }
+
+#[test]
+fn test_skip_words_keeps_the_case_of_the_rest() {
+    assert_eq!(skip_words("log export Logs/Run.txt", 2), "Logs/Run.txt");
+    assert_eq!(skip_words("  LOG   EXPORT  My Run.txt  ", 2), "My Run.txt");
+    assert_eq!(skip_words("LOG EXPORT", 2), "");
+    assert_eq!(skip_words("LOG", 2), "");
+}